@@ -1,7 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, AnchorProvider } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import { BN } from "bn.js";
 
 // Controller program constants
@@ -75,6 +75,32 @@ export class ControllerClient {
       .rpc();
   }
 
+  /**
+   * Migrate a controller store created with the original layout
+   * @param owner Keypair of the current owner, pays the extra rent
+   * @param tokenMint PublicKey of the token mint governed by the controller
+   * @param pendingValidityPeriod Seconds legacy nominations stay claimable, 0 drops them
+   * @returns Transaction signature
+   */
+  async migrateControllerStore(
+    owner: Keypair,
+    tokenMint: PublicKey,
+    pendingValidityPeriod: number = 0
+  ): Promise<string> {
+    return await this.program.methods
+      .migrateControllerStore({
+        pendingValidityPeriod: new BN(pendingValidityPeriod),
+      })
+      .accounts({
+        owner: owner.publicKey,
+        controllerStore: this.controllerStore,
+        tokenMint,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
   /**
    * Set members program
    * @param owner Keypair of the current owner
@@ -188,15 +214,18 @@ export class ControllerClient {
    * Transfer ownership of the controller program
    * @param currentOwner Keypair of the current owner
    * @param newOwner PublicKey of the new owner
+   * @param validityPeriod Seconds the new owner has to claim ownership
    * @returns Transaction signature
    */
   async transferOwnership(
     currentOwner: Keypair,
-    newOwner: PublicKey
+    newOwner: PublicKey,
+    validityPeriod: number = 7 * 24 * 60 * 60
   ): Promise<string> {
     return await this.program.methods
       .transferOwnership({
         newOwner: newOwner,
+        validityPeriod: new BN(validityPeriod),
       })
       .accounts({
         owner: currentOwner.publicKey,
@@ -227,16 +256,19 @@ export class ControllerClient {
    * @param owner Keypair of the current owner
    * @param newAuthority PublicKey of the new authority
    * @param tokenMint PublicKey of the token mint
+   * @param validityPeriod Seconds the new authority has to claim mint authority
    * @returns Transaction signature
    */
   async transferMintAuthority(
     owner: Keypair,
     newAuthority: PublicKey,
-    tokenMint: PublicKey
+    tokenMint: PublicKey,
+    validityPeriod: number = 7 * 24 * 60 * 60
   ): Promise<string> {
     return await this.program.methods
       .transferMintAuthority({
         newAuthority: newAuthority,
+        validityPeriod: new BN(validityPeriod),
       })
       .accounts({
         owner: owner.publicKey,
@@ -270,6 +302,146 @@ export class ControllerClient {
       .rpc();
   }
 
+  /**
+   * Enable the timelock; afterwards configuration changes must be queued
+   * @param owner Keypair of the current owner
+   * @param delay Minimum seconds between queueing and executing an operation
+   * @param guardian PublicKey allowed to cancel queued operations
+   * @returns Transaction signature
+   */
+  async setTimelockConfig(
+    owner: Keypair,
+    delay: number,
+    guardian: PublicKey
+  ): Promise<string> {
+    return await this.program.methods
+      .setTimelockConfig({
+        delay: new BN(delay),
+        guardian,
+      })
+      .accounts({
+        owner: owner.publicKey,
+        controllerStore: this.controllerStore,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
+  /**
+   * Derive the PDA of a timelock operation
+   * @param id Operation id
+   * @returns Timelock operation PDA
+   */
+  getTimelockOperationAddress(id: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock_operation"), id.toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Queue a timelocked configuration change
+   * @param owner Keypair of the current owner
+   * @param action Timelock action, e.g. { setFactory: { factory } }
+   * @param eta Unix timestamp from which the operation can be executed
+   * @returns Transaction signature and the operation PDA
+   */
+  async queueTimelockOperation(
+    owner: Keypair,
+    action: any,
+    eta: number
+  ): Promise<{ tx: string; timelockOperation: PublicKey }> {
+    const store = await this.getControllerStore();
+    const timelockOperation = this.getTimelockOperationAddress(
+      store.timelockOperationCounter
+    );
+    const tx = await this.program.methods
+      .queueTimelockOperation({ action, eta: new BN(eta) })
+      .accounts({
+        owner: owner.publicKey,
+        controllerStore: this.controllerStore,
+        timelockOperation,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+    return { tx, timelockOperation };
+  }
+
+  /**
+   * Execute a queued operation once its ETA has passed
+   * @param executor Keypair paying for the transaction
+   * @param timelockOperation PublicKey of the operation PDA
+   * @param proposer PublicKey receiving the operation rent
+   * @param tokenMint Token-2022 mint, only needed for a transfer hook change
+   * @returns Transaction signature
+   */
+  async executeTimelockOperation(
+    executor: Keypair,
+    timelockOperation: PublicKey,
+    proposer: PublicKey,
+    tokenMint: PublicKey | null = null
+  ): Promise<string> {
+    return await this.program.methods
+      .executeTimelockOperation()
+      .accounts({
+        executor: executor.publicKey,
+        controllerStore: this.controllerStore,
+        timelockOperation,
+        proposer,
+        tokenMint,
+        tokenProgram: tokenMint ? TOKEN_2022_PROGRAM_ID : null,
+      })
+      .signers([executor])
+      .rpc();
+  }
+
+  /**
+   * Cancel a queued operation
+   * @param authority Keypair of the guardian or owner
+   * @param timelockOperation PublicKey of the operation PDA
+   * @param proposer PublicKey receiving the operation rent
+   * @returns Transaction signature
+   */
+  async cancelTimelockOperation(
+    authority: Keypair,
+    timelockOperation: PublicKey,
+    proposer: PublicKey
+  ): Promise<string> {
+    return await this.program.methods
+      .cancelTimelockOperation()
+      .accounts({
+        authority: authority.publicKey,
+        controllerStore: this.controllerStore,
+        timelockOperation,
+        proposer,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Re-enable minting after mint authority was handed back to the controller PDA
+   * @param owner Keypair of the current owner
+   * @param tokenMint PublicKey of the token mint
+   * @returns Transaction signature
+   */
+  async reclaimMintAuthority(
+    owner: Keypair,
+    tokenMint: PublicKey
+  ): Promise<string> {
+    return await this.program.methods
+      .reclaimMintAuthority()
+      .accounts({
+        owner: owner.publicKey,
+        controllerStore: this.controllerStore,
+        tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
   /**
    * Get controller store data
    * @returns Controller store data
@@ -17,10 +17,16 @@ import { BN } from "bn.js";
 // Factory program constants
 export const FACTORY_SEED = "factory";
 export const CUSTODIAN_BTC_ADDRESS_SEED = "custodian_btc_address";
+export const CUSTODIAN_BTC_ADDRESS_BOOK_SEED = "custodian_btc_address_book";
 export const MERCHANT_BTC_ADDRESS_SEED = "merchant_btc_address";
 export const MINT_REQUEST_SEED = "mint_request";
 export const BURN_REQUEST_SEED = "burn_request";
 export const MERCHANT_INFO_SEED = "merchant_info";
+export const DENYLIST_SEED = "denylist";
+export const MERCHANT_REQUESTS_SEED = "merchant_requests";
+export const MINT_RECIPIENT_ALLOWLIST_SEED = "mint_recipient_allowlist";
+export const BTC_PAYOUT_ALLOWLIST_SEED = "btc_payout_allowlist";
+export const BURN_PAYOUTS_SEED = "burn_payouts";
 
 export class FactoryClient {
   // Program properties
@@ -80,7 +86,152 @@ export class FactoryClient {
   }
 
   /**
-   * Set custodian BTC deposit address for a merchant
+   * Migrate a factory store created with the original layout, fees start disabled
+   * @param owner Keypair of the controller owner, pays the extra rent
+   * @param controllerProgramId PublicKey of the controller program recorded in the store
+   * @returns Transaction signature
+   */
+  async migrateFactoryStore(
+    owner: Keypair,
+    controllerProgramId: PublicKey
+  ): Promise<string> {
+    // The legacy store can't be decoded with the current layout
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      controllerProgramId
+    );
+
+    return await this.program.methods
+      .migrateFactoryStore()
+      .accounts({
+        owner: owner.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
+  /**
+   * Configure the protocol fees charged on mint and burn, queued while the
+   * controller timelock is enabled
+   * @param owner Keypair of the controller owner
+   * @param mintFeeBps Fee on confirmed mints in basis points
+   * @param burnFeeBps Fee on burns in basis points
+   * @param minFee Flat minimum fee in smallest units (as BN)
+   * @param feeRecipient Associated token account receiving the fees, null disables them
+   * @returns Transaction signature
+   */
+  async setFeeConfig(
+    owner: Keypair,
+    mintFeeBps: number,
+    burnFeeBps: number,
+    minFee: anchor.BN,
+    feeRecipient: PublicKey | null
+  ): Promise<string> {
+    const factoryStoreAccount = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreAccount.controller
+    );
+
+    return await this.program.methods
+      .setFeeConfig({
+        mintFeeBps,
+        burnFeeBps,
+        minFee,
+      })
+      .accounts({
+        owner: owner.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        feeRecipient,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
+  /**
+   * Apply the queued fee configuration once its ETA has passed
+   * @returns Transaction signature
+   */
+  async applyFeeConfig(): Promise<string> {
+    return await this.program.methods
+      .applyFeeConfig()
+      .accounts({
+        factoryStore: this.factoryStore,
+      })
+      .rpc();
+  }
+
+  /**
+   * Drop the queued fee configuration
+   * @param authority Keypair of the controller guardian or owner
+   * @returns Transaction signature
+   */
+  async cancelFeeConfig(authority: Keypair): Promise<string> {
+    const factoryStoreAccount = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreAccount.controller
+    );
+
+    return await this.program.methods
+      .cancelFeeConfig()
+      .accounts({
+        authority: authority.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Resolve the fee recipient token account, or null while fees are disabled
+   * @returns PublicKey of the fee recipient or null
+   */
+  async getFeeTokenAccount(): Promise<PublicKey | null> {
+    const factoryStoreAccount = await this.getFactoryStore();
+    return factoryStoreAccount.feeRecipient.equals(PublicKey.default)
+      ? null
+      : factoryStoreAccount.feeRecipient;
+  }
+
+  /**
+   * Derive the custodian BTC address book of a merchant
+   * @param merchant PublicKey of the merchant
+   * @returns Address book PDA
+   */
+  getCustodianBtcAddressBook(merchant: PublicKey): PublicKey {
+    const [custodianBtcAddressBook] = PublicKey.findProgramAddressSync(
+      [Buffer.from(CUSTODIAN_BTC_ADDRESS_BOOK_SEED), merchant.toBuffer()],
+      this.factoryProgramId
+    );
+    return custodianBtcAddressBook;
+  }
+
+  /**
+   * Derive a custodian BTC deposit address account of a merchant
+   * @param merchant PublicKey of the merchant
+   * @param index Index of the address in the merchant's address book
+   * @returns Deposit address PDA
+   */
+  getCustodianBtcAddress(merchant: PublicKey, index: number): PublicKey {
+    const [custodianBtcAddress] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(CUSTODIAN_BTC_ADDRESS_SEED),
+        merchant.toBuffer(),
+        Buffer.from(new Uint8Array(new BN(index).toArray("le", 4))),
+      ],
+      this.factoryProgramId
+    );
+    return custodianBtcAddress;
+  }
+
+  /**
+   * Set custodian BTC deposit address for a merchant, previous addresses stay active until retired
    * @param custodian PublicKey of the custodian
    * @param merchant PublicKey of the merchant
    * @param btcDepositAddress BTC deposit address as string
@@ -93,10 +244,15 @@ export class FactoryClient {
     btcDepositAddress: string,
     membersProgramId: PublicKey
   ): Promise<string> {
-    // Derive custodian BTC address account
-    const [custodianBtcAddress] = PublicKey.findProgramAddressSync(
-      [Buffer.from(CUSTODIAN_BTC_ADDRESS_SEED), merchant.toBuffer()],
-      this.factoryProgramId
+    // The new address takes the next index of the merchant's address book
+    const custodianBtcAddressBook = this.getCustodianBtcAddressBook(merchant);
+    // @ts-ignore - account types would normally be available
+    const book = await this.program.account.custodianBtcAddressBook.fetchNullable(
+      custodianBtcAddressBook
+    );
+    const custodianBtcAddress = this.getCustodianBtcAddress(
+      merchant,
+      book ? book.addressCount : 0
     );
 
     // Derive merchant info account
@@ -132,6 +288,7 @@ export class FactoryClient {
         controllerStore,
         membersStore,
         merchantInfo,
+        custodianBtcAddressBook,
         custodianBtcAddress,
         systemProgram: SystemProgram.programId,
       })
@@ -139,6 +296,144 @@ export class FactoryClient {
       .rpc();
   }
 
+  /**
+   * Retire a custodian BTC deposit address of a merchant
+   * @param custodian Keypair of the custodian
+   * @param merchant PublicKey of the merchant
+   * @param index Index of the address to retire
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async retireCustodianBtcDepositAddress(
+    custodian: Keypair,
+    merchant: PublicKey,
+    index: number,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const factoryStoreInfo = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreInfo.controller
+    );
+    const [membersStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("members")],
+      membersProgramId
+    );
+
+    return await this.program.methods
+      .retireCustodianBtcDepositAddress({
+        merchant,
+        index,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+        custodianBtcAddress: this.getCustodianBtcAddress(merchant, index),
+      })
+      .signers([custodian])
+      .rpc();
+  }
+
+  /**
+   * Move a merchant's BTC deposit address from the original single address
+   * layout to index 0 of its address book
+   * @param custodian Keypair of the custodian
+   * @param merchant PublicKey of the merchant
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async migrateCustodianBtcDepositAddress(
+    custodian: Keypair,
+    merchant: PublicKey,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const factoryStoreInfo = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreInfo.controller
+    );
+    const [membersStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("members")],
+      membersProgramId
+    );
+    const [legacyCustodianBtcAddress] = PublicKey.findProgramAddressSync(
+      [Buffer.from(CUSTODIAN_BTC_ADDRESS_SEED), merchant.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .migrateCustodianBtcDepositAddress({ merchant })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+        legacyCustodianBtcAddress,
+        custodianBtcAddressBook: this.getCustodianBtcAddressBook(merchant),
+        custodianBtcAddress: this.getCustodianBtcAddress(merchant, 0),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+  }
+
+  /**
+   * Migrate a request opened with the original layout, its hash is recomputed
+   * @param custodian Keypair of the custodian, pays the extra rent
+   * @param nonce Request nonce
+   * @param isMintRequest Whether it is a mint request (true) or a burn request (false)
+   * @param requester PublicKey of the merchant that opened the request
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async migrateRequest(
+    custodian: Keypair,
+    nonce: number,
+    isMintRequest: boolean,
+    requester: PublicKey,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const factoryStoreInfo = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreInfo.controller
+    );
+    const [membersStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("members")],
+      membersProgramId
+    );
+    // The legacy request can't be decoded with the current layout
+    const requestSeed = isMintRequest ? MINT_REQUEST_SEED : BURN_REQUEST_SEED;
+    const [requestAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from(requestSeed), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      this.factoryProgramId
+    );
+    const [merchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MERCHANT_REQUESTS_SEED), requester.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .migrateRequest({
+        requestType: isMintRequest ? { mint: {} } : { burn: {} },
+        nonce: new BN(nonce),
+        requester,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+        requestAccount,
+        merchantRequests,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+  }
+
   /**
    * Set merchant BTC deposit address
    * @param merchant Keypair of the merchant
@@ -216,14 +511,14 @@ export class FactoryClient {
   /**
    * Get custodian BTC deposit address for a merchant
    * @param merchant PublicKey of the merchant
+   * @param index Index of the address in the merchant's address book
    * @returns Custodian BTC deposit address data
    */
-  async getCustodianBtcDepositAddress(merchant: PublicKey): Promise<any> {
-    // Derive custodian BTC address PDA
-    const [custodianBtcAddress] = PublicKey.findProgramAddressSync(
-      [Buffer.from(CUSTODIAN_BTC_ADDRESS_SEED), merchant.toBuffer()],
-      this.factoryProgramId
-    );
+  async getCustodianBtcDepositAddress(
+    merchant: PublicKey,
+    index: number
+  ): Promise<any> {
+    const custodianBtcAddress = this.getCustodianBtcAddress(merchant, index);
 
     try {
       // @ts-ignore - account types would normally be available
@@ -235,6 +530,26 @@ export class FactoryClient {
     }
   }
 
+  /**
+   * Get all custodian BTC deposit addresses of a merchant, active and retired
+   * @param merchant PublicKey of the merchant
+   * @returns Custodian BTC deposit address data ordered by index
+   */
+  async getCustodianBtcDepositAddresses(merchant: PublicKey): Promise<any[]> {
+    // @ts-ignore - account types would normally be available
+    const book = await this.program.account.custodianBtcAddressBook.fetchNullable(
+      this.getCustodianBtcAddressBook(merchant)
+    );
+    if (!book) {
+      return [];
+    }
+    const addresses = [];
+    for (let index = 0; index < book.addressCount; index++) {
+      addresses.push(await this.getCustodianBtcDepositAddress(merchant, index));
+    }
+    return addresses;
+  }
+
   /**
    * Get factory store data
    * @returns Factory store data
@@ -397,6 +712,71 @@ export class FactoryClient {
     return result.data;
   }
 
+  /**
+   * Allow a wallet to receive the merchant's mints
+   * @param merchant Keypair of the merchant
+   * @param recipient PublicKey of the wallet
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async addMintRecipient(
+    merchant: Keypair,
+    recipient: PublicKey,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const factoryStoreInfo = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreInfo.controller
+    );
+    const [merchantInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MERCHANT_INFO_SEED), merchant.publicKey.toBuffer()],
+      membersProgramId
+    );
+    const [mintRecipientAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MINT_RECIPIENT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .addMintRecipient({ recipient })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        merchantInfo,
+        mintRecipientAllowlist,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+  }
+
+  /**
+   * Remove a wallet from the merchant's mint recipients
+   * @param merchant Keypair of the merchant
+   * @param recipient PublicKey of the wallet
+   * @returns Transaction signature
+   */
+  async removeMintRecipient(
+    merchant: Keypair,
+    recipient: PublicKey
+  ): Promise<string> {
+    const [mintRecipientAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MINT_RECIPIENT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .removeMintRecipient({ recipient })
+      .accounts({
+        payer: merchant.publicKey,
+        mintRecipientAllowlist,
+      })
+      .signers([merchant])
+      .rpc();
+  }
+
   /**
    * Add mint request
    * @param merchant Keypair of the merchant
@@ -404,6 +784,8 @@ export class FactoryClient {
    * @param btcTxId Bitcoin transaction ID as string
    * @param btcDepositAddress BTC deposit address as string
    * @param membersProgramId PublicKey of the members program
+   * @param recipient Optional wallet on the merchant allowlist to mint to instead of the merchant
+   * @param btcDepositTimestamp Optional unix time the BTC was sent, defaults to the request time
    * @returns Transaction signature
    */
   async addMintRequest(
@@ -411,7 +793,9 @@ export class FactoryClient {
     amount: anchor.BN,
     btcTxId: string,
     btcDepositAddress: string,
-    membersProgramId: PublicKey
+    membersProgramId: PublicKey,
+    recipient: PublicKey | null = null,
+    btcDepositTimestamp: anchor.BN | null = null
   ): Promise<string> {
     // 获取 factory store 信息来获取当前请求计数器和controller
     const factoryStoreInfo = await this.getFactoryStore();
@@ -441,9 +825,30 @@ export class FactoryClient {
       membersProgramId
     );
 
-    // Derive custodian BTC address account
-    const [custodianBtcAddress] = PublicKey.findProgramAddressSync(
-      [Buffer.from(CUSTODIAN_BTC_ADDRESS_SEED), merchant.publicKey.toBuffer()],
+    // Derive the merchant's denylist entry, it must not exist
+    const [requesterDenylistEntry] = PublicKey.findProgramAddressSync(
+      [Buffer.from(DENYLIST_SEED), merchant.publicKey.toBuffer()],
+      membersProgramId
+    );
+
+    // Look up the index of the deposit address, the latest match wins
+    const custodianAddresses = await this.getCustodianBtcDepositAddresses(
+      merchant.publicKey
+    );
+    const deposit = custodianAddresses
+      .filter((address) => address.btcAddress === btcDepositAddress)
+      .pop();
+    if (!deposit) {
+      throw new Error(`Unknown custodian BTC deposit address: ${btcDepositAddress}`);
+    }
+    const custodianBtcAddress = this.getCustodianBtcAddress(
+      merchant.publicKey,
+      deposit.index
+    );
+
+    // A designated recipient is checked against the merchant's allowlist
+    const [mintRecipientAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MINT_RECIPIENT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
       this.factoryProgramId
     );
 
@@ -452,6 +857,8 @@ export class FactoryClient {
         amount,
         btcTxid: btcTxId,
         btcDepositAddress: btcDepositAddress,
+        recipient,
+        btcDepositTimestamp,
       })
       .accounts({
         payer: merchant.publicKey,
@@ -459,7 +866,9 @@ export class FactoryClient {
         requestAccount,
         controllerStore,
         merchantInfo,
+        requesterDenylistEntry,
         custodianBtcAddress,
+        mintRecipientAllowlist: recipient ? mintRecipientAllowlist : null,
         systemProgram: SystemProgram.programId,
       })
       .signers([merchant])
@@ -538,11 +947,6 @@ export class FactoryClient {
       this.factoryProgramId
     );
 
-    // Derive token accounts and other needed accounts
-    const merchantTokenAccount = await anchor.utils.token.associatedAddress({
-      mint: tokenMint,
-      owner: merchant,
-    });
 
     // 首先获取请求账户的详细信息以获取请求哈希
     try {
@@ -552,6 +956,13 @@ export class FactoryClient {
       );
       const requestHash = requestData.hash;
 
+      // Mints go to the request's recipient, the merchant unless it designated another
+      const recipient: PublicKey = requestData.recipient;
+      const recipientTokenAccount = anchor.utils.token.associatedAddress({
+        mint: tokenMint,
+        owner: recipient,
+      });
+
       // Derive members store account
       const [membersStore] = PublicKey.findProgramAddressSync(
         [Buffer.from("members")],
@@ -564,6 +975,12 @@ export class FactoryClient {
         controllerProgramId
       );
 
+      // Derive the recipient's denylist entry, it must not exist
+      const [toAddressDenylistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from(DENYLIST_SEED), recipient.toBuffer()],
+        membersProgramId
+      );
+
       return await this.program.methods
         .confirmMintRequest({
           nonce: nonceBN,
@@ -576,8 +993,10 @@ export class FactoryClient {
           controllerStore,
           membersStore,
           tokenMint,
-          toAddress: merchant,
-          tokenAccount: merchantTokenAccount,
+          toAddress: recipient,
+          toAddressDenylistEntry,
+          tokenAccount: recipientTokenAccount,
+          feeTokenAccount: await this.getFeeTokenAccount(),
           controllerProgram: controllerProgramId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -591,6 +1010,104 @@ export class FactoryClient {
     }
   }
 
+  /**
+   * Confirm mint request for less than the requested amount
+   * @param custodian Keypair of the custodian
+   * @param merchant PublicKey of the merchant
+   * @param nonce Request nonce
+   * @param amount Amount actually minted in smallest units (as BN)
+   * @param reason Reason for the adjustment
+   * @param tokenMint PublicKey of the token mint
+   * @param controllerProgramId PublicKey of the controller program
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async confirmMintRequestPartial(
+    custodian: Keypair,
+    merchant: PublicKey,
+    nonce: number,
+    amount: anchor.BN,
+    reason: string,
+    tokenMint: PublicKey,
+    controllerProgramId: PublicKey,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    // Convert nonce to BN
+    const nonceBN = new BN(nonce);
+
+    // Derive request account using the correct nonce format to match Rust side
+    const nonceBuffer = Buffer.from(new Uint8Array(nonceBN.toArray("le", 8)));
+
+    const [requestAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MINT_REQUEST_SEED), nonceBuffer],
+      this.factoryProgramId
+    );
+
+
+    // 首先获取请求账户的详细信息以获取请求哈希
+    try {
+      // @ts-ignore - account types would normally be available
+      const requestData = await this.program.account.requestAccount.fetch(
+        requestAccount
+      );
+      const requestHash = requestData.hash;
+
+      // Mints go to the request's recipient, the merchant unless it designated another
+      const recipient: PublicKey = requestData.recipient;
+      const recipientTokenAccount = anchor.utils.token.associatedAddress({
+        mint: tokenMint,
+        owner: recipient,
+      });
+
+      // Derive members store account
+      const [membersStore] = PublicKey.findProgramAddressSync(
+        [Buffer.from("members")],
+        membersProgramId
+      );
+
+      // Derive controller store account
+      const [controllerStore] = PublicKey.findProgramAddressSync(
+        [Buffer.from("controller")],
+        controllerProgramId
+      );
+
+      // Derive the recipient's denylist entry, it must not exist
+      const [toAddressDenylistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from(DENYLIST_SEED), recipient.toBuffer()],
+        membersProgramId
+      );
+
+      return await this.program.methods
+        .confirmMintRequestPartial({
+          nonce: nonceBN,
+          requestHash: requestHash,
+          amount,
+          reason,
+        })
+        .accountsStrict({
+          payer: custodian.publicKey,
+          factoryStore: this.factoryStore,
+          requestAccount,
+          controllerStore,
+          membersStore,
+          tokenMint,
+          toAddress: recipient,
+          toAddressDenylistEntry,
+          tokenAccount: recipientTokenAccount,
+          feeTokenAccount: await this.getFeeTokenAccount(),
+          controllerProgram: controllerProgramId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          instructionSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([custodian])
+        .rpc();
+    } catch (error) {
+      throw new Error(`Failed to partially confirm mint request: ${error}`);
+    }
+  }
+
   /**
    * Reject mint request
    * @param custodian Keypair of the custodian
@@ -645,6 +1162,214 @@ export class FactoryClient {
     }
   }
 
+  /**
+   * Fetch the mint requests of a batch with their PDAs
+   * @param nonces Request nonces
+   * @returns Request PDAs and data
+   */
+  private async getMintRequestBatch(
+    nonces: number[]
+  ): Promise<{ requestAccount: PublicKey; requestData: any }[]> {
+    return Promise.all(
+      nonces.map(async (nonce) => {
+        const [requestAccount] = PublicKey.findProgramAddressSync(
+          [Buffer.from(MINT_REQUEST_SEED), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+          this.factoryProgramId
+        );
+        // @ts-ignore - account types would normally be available
+        const requestData = await this.program.account.requestAccount.fetch(
+          requestAccount
+        );
+        return { requestAccount, requestData };
+      })
+    );
+  }
+
+  /**
+   * Confirm several mint requests in one instruction
+   * @param custodian Keypair of the custodian
+   * @param nonces Request nonces
+   * @param tokenMint PublicKey of the token mint
+   * @param controllerProgramId PublicKey of the controller program
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async confirmMintRequests(
+    custodian: Keypair,
+    nonces: number[],
+    tokenMint: PublicKey,
+    controllerProgramId: PublicKey,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const batch = await this.getMintRequestBatch(nonces);
+
+    const [membersStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("members")],
+      membersProgramId
+    );
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      controllerProgramId
+    );
+
+    // Request PDA, merchant requests PDA, recipient ATA and recipient denylist PDA per request
+    const remainingAccounts = batch.flatMap(({ requestAccount, requestData }) => {
+      const requester: PublicKey = requestData.requester;
+      const recipient: PublicKey = requestData.recipient;
+      const [merchantRequests] = PublicKey.findProgramAddressSync(
+        [Buffer.from(MERCHANT_REQUESTS_SEED), requester.toBuffer()],
+        this.factoryProgramId
+      );
+      const [denylistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from(DENYLIST_SEED), recipient.toBuffer()],
+        membersProgramId
+      );
+      const tokenAccount = anchor.utils.token.associatedAddress({
+        mint: tokenMint,
+        owner: recipient,
+      });
+      return [
+        { pubkey: requestAccount, isSigner: false, isWritable: true },
+        { pubkey: merchantRequests, isSigner: false, isWritable: true },
+        { pubkey: tokenAccount, isSigner: false, isWritable: true },
+        { pubkey: denylistEntry, isSigner: false, isWritable: false },
+      ];
+    });
+
+    return await this.program.methods
+      .confirmMintRequests({
+        requests: batch.map(({ requestData }) => ({
+          nonce: requestData.nonce,
+          requestHash: requestData.hash,
+        })),
+      })
+      .accountsStrict({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+        tokenMint,
+        feeTokenAccount: await this.getFeeTokenAccount(),
+        controllerProgram: controllerProgramId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([custodian])
+      .rpc();
+  }
+
+  /**
+   * Reject several mint requests in one instruction
+   * @param custodian Keypair of the custodian
+   * @param nonces Request nonces
+   * @param membersStore PublicKey of the members store
+   * @param controllerStore PublicKey of the controller store
+   * @returns Transaction signature
+   */
+  async rejectMintRequests(
+    custodian: Keypair,
+    nonces: number[],
+    membersStore: PublicKey,
+    controllerStore: PublicKey
+  ): Promise<string> {
+    const batch = await this.getMintRequestBatch(nonces);
+
+    // Request PDA and merchant requests PDA per request
+    const remainingAccounts = batch.flatMap(({ requestAccount, requestData }) => {
+      const [merchantRequests] = PublicKey.findProgramAddressSync(
+        [Buffer.from(MERCHANT_REQUESTS_SEED), requestData.requester.toBuffer()],
+        this.factoryProgramId
+      );
+      return [
+        { pubkey: requestAccount, isSigner: false, isWritable: true },
+        { pubkey: merchantRequests, isSigner: false, isWritable: true },
+      ];
+    });
+
+    return await this.program.methods
+      .rejectMintRequests({
+        requests: batch.map(({ requestData }) => ({
+          nonce: requestData.nonce,
+          requestHash: requestData.hash,
+        })),
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([custodian])
+      .rpc();
+  }
+
+  /**
+   * Allow a BTC address to receive the merchant's burn payouts
+   * @param merchant Keypair of the merchant
+   * @param btcAddress BTC address as string
+   * @param membersProgramId PublicKey of the members program
+   * @returns Transaction signature
+   */
+  async addBtcPayoutAddress(
+    merchant: Keypair,
+    btcAddress: string,
+    membersProgramId: PublicKey
+  ): Promise<string> {
+    const factoryStoreInfo = await this.getFactoryStore();
+    const [controllerStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("controller")],
+      factoryStoreInfo.controller
+    );
+    const [merchantInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MERCHANT_INFO_SEED), merchant.publicKey.toBuffer()],
+      membersProgramId
+    );
+    const [btcPayoutAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(BTC_PAYOUT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .addBtcPayoutAddress({ btcAddress })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        merchantInfo,
+        btcPayoutAllowlist,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+  }
+
+  /**
+   * Remove a BTC address from the merchant's burn payout addresses
+   * @param merchant Keypair of the merchant
+   * @param btcAddress BTC address as string
+   * @returns Transaction signature
+   */
+  async removeBtcPayoutAddress(
+    merchant: Keypair,
+    btcAddress: string
+  ): Promise<string> {
+    const [btcPayoutAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(BTC_PAYOUT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
+      this.factoryProgramId
+    );
+
+    return await this.program.methods
+      .removeBtcPayoutAddress({ btcAddress })
+      .accounts({
+        payer: merchant.publicKey,
+        btcPayoutAllowlist,
+      })
+      .signers([merchant])
+      .rpc();
+  }
+
   /**
    * Burn tokens
    * @param merchant Keypair of the merchant
@@ -652,6 +1377,7 @@ export class FactoryClient {
    * @param membersStore PublicKey of the members store
    * @param tokenMint PublicKey of the token mint
    * @param membersProgramId PublicKey of the members program
+   * @param btcAddress Optional BTC address from the merchant allowlist to pay out to
    * @returns Transaction signature
    */
   async burn(
@@ -659,7 +1385,8 @@ export class FactoryClient {
     amount: anchor.BN,
     membersStore: PublicKey,
     tokenMint: PublicKey,
-    membersProgramId: PublicKey
+    membersProgramId: PublicKey,
+    btcAddress: string | null = null
   ): Promise<string> {
     // 获取当前的 factory store 状态以获取 burn_request_counter 和 controller
     const factoryStoreAccount = await this.getFactoryStore();
@@ -688,7 +1415,13 @@ export class FactoryClient {
       membersProgramId
     );
 
-    // Derive merchant BTC address account
+    // Derive the merchant's denylist entry, it must not exist
+    const [requesterDenylistEntry] = PublicKey.findProgramAddressSync(
+      [Buffer.from(DENYLIST_SEED), merchant.publicKey.toBuffer()],
+      membersProgramId
+    );
+
+    // Derive merchant BTC address account, only needed without a payout address
     const [merchantBtcAddress] = PublicKey.findProgramAddressSync(
       [Buffer.from(MERCHANT_BTC_ADDRESS_SEED), merchant.publicKey.toBuffer()],
       this.factoryProgramId
@@ -706,9 +1439,16 @@ export class FactoryClient {
       owner: controllerStore,
     });
 
+    // A per-request payout address is checked against the merchant's allowlist
+    const [btcPayoutAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from(BTC_PAYOUT_ALLOWLIST_SEED), merchant.publicKey.toBuffer()],
+      this.factoryProgramId
+    );
+
     return await this.program.methods
       .burn({
         amount,
+        btcAddress,
       })
       .accounts({
         payer: merchant.publicKey,
@@ -717,9 +1457,12 @@ export class FactoryClient {
         controllerStore,
         membersStore,
         merchantInfo,
-        merchantBtcAddress,
+        requesterDenylistEntry,
+        merchantBtcAddress: btcAddress ? null : merchantBtcAddress,
+        btcPayoutAllowlist: btcAddress ? btcPayoutAllowlist : null,
         tokenMint,
         tokenAccount: merchantTokenAccount,
+        feeTokenAccount: await this.getFeeTokenAccount(),
         controllerTokenAccount,
         controllerProgram: controller,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -736,6 +1479,8 @@ export class FactoryClient {
    * @param custodian Keypair of the custodian
    * @param merchant PublicKey of the merchant
    * @param btcTxId Bitcoin transaction ID as string
+   * @param btcAmountSent BTC amount that reached the merchant, in satoshis (as BN)
+   * @param btcNetworkFee BTC miner fee paid for the payout, in satoshis (as BN)
    * @param nonce Request nonce
    * @param membersStore PublicKey of the members store
    * @param controllerStore PublicKey of the controller store
@@ -745,6 +1490,8 @@ export class FactoryClient {
     custodian: Keypair,
     merchant: PublicKey,
     btcTxId: string,
+    btcAmountSent: anchor.BN,
+    btcNetworkFee: anchor.BN,
     nonce: number,
     membersStore: PublicKey,
     controllerStore: PublicKey
@@ -768,9 +1515,17 @@ export class FactoryClient {
       );
       const requestHash = requestData.hash;
 
+      // Partial payouts are listed in the burn payouts PDA
+      const [burnPayouts] = PublicKey.findProgramAddressSync(
+        [Buffer.from(BURN_PAYOUTS_SEED), nonceBuffer],
+        this.factoryProgramId
+      );
+
       return await this.program.methods
         .confirmBurnRequest({
           btcTxid: btcTxId,
+          btcAmountSent,
+          btcNetworkFee,
           nonce: new anchor.BN(nonce),
           requestHash: requestHash,
         })
@@ -780,6 +1535,7 @@ export class FactoryClient {
           requestAccount,
           controllerStore,
           membersStore,
+          burnPayouts: requestData.btcAmountSent.isZero() ? null : burnPayouts,
         })
         .signers([custodian])
         .rpc();
@@ -788,6 +1544,140 @@ export class FactoryClient {
     }
   }
 
+  /**
+   * Record one of several BTC payouts for a burn request
+   * @param custodian Keypair of the custodian
+   * @param merchant PublicKey of the merchant
+   * @param btcTxId Bitcoin transaction ID as string
+   * @param btcAmountSent BTC amount that reached the merchant, in satoshis (as BN)
+   * @param btcNetworkFee BTC miner fee paid for the payout, in satoshis (as BN)
+   * @param nonce Request nonce
+   * @param membersStore PublicKey of the members store
+   * @param controllerStore PublicKey of the controller store
+   * @returns Transaction signature
+   */
+  async recordBurnPayout(
+    custodian: Keypair,
+    merchant: PublicKey,
+    btcTxId: string,
+    btcAmountSent: anchor.BN,
+    btcNetworkFee: anchor.BN,
+    nonce: number,
+    membersStore: PublicKey,
+    controllerStore: PublicKey
+  ): Promise<string> {
+    // Convert nonce to BN
+    const nonceBN = new BN(nonce);
+
+    // Derive request account using the correct nonce format to match Rust side
+    const nonceBuffer = Buffer.from(new Uint8Array(nonceBN.toArray("le", 8)));
+
+    const [requestAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from(BURN_REQUEST_SEED), nonceBuffer],
+      this.factoryProgramId
+    );
+
+    // 首先获取请求账户的详细信息以获取请求哈希
+    try {
+      // @ts-ignore - account types would normally be available
+      const requestData = await this.program.account.requestAccount.fetch(
+        requestAccount
+      );
+      const requestHash = requestData.hash;
+
+      return await this.program.methods
+        .recordBurnPayout({
+          btcTxid: btcTxId,
+          btcAmountSent,
+          btcNetworkFee,
+          nonce: new anchor.BN(nonce),
+          requestHash: requestHash,
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore: this.factoryStore,
+          requestAccount,
+          burnPayouts: PublicKey.findProgramAddressSync(
+            [Buffer.from(BURN_PAYOUTS_SEED), nonceBuffer],
+            this.factoryProgramId
+          )[0],
+          controllerStore,
+          membersStore,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([custodian])
+        .rpc();
+    } catch (error) {
+      throw new Error(`Failed to record burn payout: ${error}`);
+    }
+  }
+
+  /**
+   * Confirm several burn requests paid out by one BTC transaction
+   * @param custodian Keypair of the custodian
+   * @param btcTxId Bitcoin transaction ID as string
+   * @param payouts Request nonce, output index, amount sent and network fee share per burn
+   * @param membersStore PublicKey of the members store
+   * @param controllerStore PublicKey of the controller store
+   * @returns Transaction signature
+   */
+  async confirmBurnRequests(
+    custodian: Keypair,
+    btcTxId: string,
+    payouts: {
+      nonce: number;
+      vout: number;
+      btcAmountSent: anchor.BN;
+      btcNetworkFee: anchor.BN;
+    }[],
+    membersStore: PublicKey,
+    controllerStore: PublicKey
+  ): Promise<string> {
+    const batch = await Promise.all(
+      payouts.map(async (payout) => {
+        const [requestAccount] = PublicKey.findProgramAddressSync(
+          [Buffer.from(BURN_REQUEST_SEED), new BN(payout.nonce).toArrayLike(Buffer, "le", 8)],
+          this.factoryProgramId
+        );
+        // @ts-ignore - account types would normally be available
+        const requestData = await this.program.account.requestAccount.fetch(
+          requestAccount
+        );
+        const [merchantRequests] = PublicKey.findProgramAddressSync(
+          [Buffer.from(MERCHANT_REQUESTS_SEED), requestData.requester.toBuffer()],
+          this.factoryProgramId
+        );
+        return { payout, requestAccount, requestData, merchantRequests };
+      })
+    );
+
+    return await this.program.methods
+      .confirmBurnRequests({
+        btcTxid: btcTxId,
+        requests: batch.map(({ payout, requestData }) => ({
+          nonce: new anchor.BN(payout.nonce),
+          requestHash: requestData.hash,
+          vout: payout.vout,
+          btcAmountSent: payout.btcAmountSent,
+          btcNetworkFee: payout.btcNetworkFee,
+        })),
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore: this.factoryStore,
+        controllerStore,
+        membersStore,
+      })
+      .remainingAccounts(
+        batch.flatMap(({ requestAccount, merchantRequests }) => [
+          { pubkey: requestAccount, isSigner: false, isWritable: true },
+          { pubkey: merchantRequests, isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([custodian])
+      .rpc();
+  }
+
   /**
    * Close a mint or burn request account
    * @param payer Keypair of the account that will pay for the transaction
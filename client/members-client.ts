@@ -67,6 +67,61 @@ export class MembersClient {
       .rpc();
   }
 
+  /**
+   * Migrate a members store created with the original layout to the current one
+   * @param owner Keypair of the current owner of the members program
+   * @param factory PublicKey of the factory program merchants resign through
+   * @param complianceAuthority PublicKey of the compliance authority
+   * @param pendingOwnerValidityPeriod Seconds a pending ownership nomination stays claimable, 0 drops it
+   * @returns Transaction signature
+   */
+  async migrateMembersStore(
+    owner: Keypair,
+    factory: PublicKey,
+    complianceAuthority: PublicKey,
+    pendingOwnerValidityPeriod: number = 0
+  ): Promise<string> {
+    return await this.program.methods
+      .migrateMembersStore({
+        factory,
+        complianceAuthority,
+        pendingOwnerValidityPeriod: new anchor.BN(pendingOwnerValidityPeriod),
+      })
+      .accounts({
+        owner: owner.publicKey,
+        membersStore: this.membersStore,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
+  /**
+   * Migrate a merchant info created with the original layout
+   * @param owner Keypair of the current owner, pays the extra rent
+   * @param merchant PublicKey of the merchant
+   * @returns Transaction signature
+   */
+  async migrateMerchantInfo(
+    owner: Keypair,
+    merchant: PublicKey
+  ): Promise<string> {
+    const [merchantInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from(MERCHANT_INFO_SEED), merchant.toBuffer()],
+      this.membersProgramId
+    );
+    return await this.program.methods
+      .migrateMerchantInfo({ merchant })
+      .accounts({
+        owner: owner.publicKey,
+        membersStore: this.membersStore,
+        merchantInfo,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
   /**
    * Set custodian for the members program
    * @param owner Keypair of the current owner of the members program
@@ -145,15 +200,18 @@ export class MembersClient {
    * Transfer ownership of the members program
    * @param currentOwner Keypair of the current owner
    * @param newOwner PublicKey of the new owner
+   * @param validityPeriod Seconds the new owner has to claim ownership
    * @returns Transaction signature
    */
   async transferOwnership(
     currentOwner: Keypair,
-    newOwner: PublicKey
+    newOwner: PublicKey,
+    validityPeriod: number = 7 * 24 * 60 * 60
   ): Promise<string> {
     return await this.program.methods
       .transferOwnership({
         newOwner,
+        validityPeriod: new anchor.BN(validityPeriod),
       })
       .accounts({
         owner: currentOwner.publicKey,
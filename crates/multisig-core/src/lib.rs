@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+
+// Seeds constants
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+pub const MULTISIG_AUTHORITY_SEED: &[u8] = b"multisig_authority";
+pub const MULTISIG_PROPOSAL_SEED: &[u8] = b"multisig_proposal";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MultisigSignersParams {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateMultisigProposalParams {
+    // Instruction of the owning program to run with the multisig authority as signer
+    pub accounts: Vec<ProposalAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+// Each program maps these onto its own error codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultisigError {
+    InvalidSigners,
+    InvalidThreshold,
+    InvalidProposal,
+    AlreadyApproved,
+    ThresholdNotMet,
+    StaleProposal,
+    MathOverflow,
+}
+
+pub fn validate_signers(
+    signers: &[Pubkey],
+    threshold: u8,
+) -> std::result::Result<(), MultisigError> {
+    if signers.is_empty() || signers.len() > MAX_MULTISIG_SIGNERS {
+        return Err(MultisigError::InvalidSigners);
+    }
+    let mut unique = signers.to_vec();
+    unique.sort();
+    unique.dedup();
+    if unique.len() != signers.len() || unique.contains(&Pubkey::default()) {
+        return Err(MultisigError::InvalidSigners);
+    }
+    if threshold == 0 || threshold as usize > signers.len() {
+        return Err(MultisigError::InvalidThreshold);
+    }
+    Ok(())
+}
+
+pub fn validate_proposal(
+    params: &CreateMultisigProposalParams,
+) -> std::result::Result<(), MultisigError> {
+    if params.accounts.len() > MAX_PROPOSAL_ACCOUNTS || params.data.len() > MAX_PROPOSAL_DATA_LEN {
+        return Err(MultisigError::InvalidProposal);
+    }
+    Ok(())
+}
+
+// Returns the id for a new proposal and advances the counter
+pub fn next_proposal_id(proposal_count: &mut u64) -> std::result::Result<u64, MultisigError> {
+    let id = *proposal_count;
+    *proposal_count = id.checked_add(1).ok_or(MultisigError::MathOverflow)?;
+    Ok(id)
+}
+
+pub fn next_generation(generation: u32) -> std::result::Result<u32, MultisigError> {
+    generation.checked_add(1).ok_or(MultisigError::MathOverflow)
+}
+
+pub fn approve(
+    proposal_generation: u32,
+    generation: u32,
+    approvals: &mut Vec<Pubkey>,
+    signer: Pubkey,
+) -> std::result::Result<(), MultisigError> {
+    if proposal_generation != generation {
+        return Err(MultisigError::StaleProposal);
+    }
+    if approvals.contains(&signer) {
+        return Err(MultisigError::AlreadyApproved);
+    }
+    approvals.push(signer);
+    Ok(())
+}
+
+pub fn ensure_executable(
+    approvals: &[Pubkey],
+    proposal_generation: u32,
+    generation: u32,
+    threshold: u8,
+) -> std::result::Result<(), MultisigError> {
+    if proposal_generation != generation {
+        return Err(MultisigError::StaleProposal);
+    }
+    if approvals.len() < threshold as usize {
+        return Err(MultisigError::ThresholdNotMet);
+    }
+    Ok(())
+}
+
+// Checks the proposal can run and rebuilds its instruction against the passed accounts
+pub fn proposal_instruction(
+    program_id: Pubkey,
+    accounts: &[ProposalAccountMeta],
+    data: &[u8],
+    remaining_accounts: &[AccountInfo],
+) -> std::result::Result<Instruction, MultisigError> {
+    if remaining_accounts.len() != accounts.len() {
+        return Err(MultisigError::InvalidProposal);
+    }
+    if remaining_accounts
+        .iter()
+        .zip(accounts.iter())
+        .any(|(account_info, meta)| account_info.key() != meta.pubkey)
+    {
+        return Err(MultisigError::InvalidProposal);
+    }
+
+    Ok(Instruction {
+        program_id,
+        accounts: accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    })
+}
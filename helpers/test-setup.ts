@@ -243,6 +243,32 @@ export async function configureController(
   console.log("Controller's Factory set to:", factoryProgram.toString());
 }
 
+// Configure Members' Factory association
+export async function configureMembers(
+  program: Program<Members>,
+  factoryProgram: PublicKey,
+  wallet: anchor.Wallet
+) {
+  if (!sharedState.membersStore) {
+    throw new Error("Members not yet initialized");
+  }
+
+  const walletKP = Keypair.fromSecretKey(wallet.payer.secretKey);
+
+  // Set Factory
+  await program.methods
+    .setFactory({
+      factory: factoryProgram,
+    })
+    .accounts({
+      owner: wallet.publicKey,
+      membersStore: sharedState.membersStore,
+    })
+    .signers([walletKP])
+    .rpc();
+  console.log("Members' Factory set to:", factoryProgram.toString());
+}
+
 // Set Custodian
 export async function setCustodian(
   program: Program<Members>,
@@ -295,4 +321,70 @@ export async function addMerchant(
     .signers([walletKP])
     .rpc();
   console.log("Merchant added:", merchant.toString());
+
+  // New merchants are not compliant until the compliance authority says so
+  await program.methods
+    .setComplianceAuthority({
+      complianceAuthority: wallet.publicKey,
+    })
+    .accounts({
+      owner: wallet.publicKey,
+      membersStore: sharedState.membersStore,
+    })
+    .signers([walletKP])
+    .rpc();
+  const [merchantInfo] = PublicKey.findProgramAddressSync(
+    [Buffer.from("merchant_info"), merchant.toBuffer()],
+    program.programId
+  );
+  await program.methods
+    .setMerchantCompliance({
+      merchant,
+      complianceValidUntil: new anchor.BN(
+        Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60
+      ),
+    })
+    .accounts({
+      complianceAuthority: wallet.publicKey,
+      membersStore: sharedState.membersStore,
+      merchantInfo,
+    })
+    .signers([walletKP])
+    .rpc();
+}
+
+// Derive the Members denylist PDA of an address
+export function getDenylistEntry(
+  program: Program<Members>,
+  address: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("denylist"), address.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getCustodianBtcAddressBook(
+  program: Program<Factory>,
+  merchant: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("custodian_btc_address_book"), merchant.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function getCustodianBtcAddress(
+  program: Program<Factory>,
+  merchant: PublicKey,
+  index: number
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("custodian_btc_address"),
+      merchant.toBuffer(),
+      new anchor.BN(index).toArrayLike(Buffer, "le", 4),
+    ],
+    program.programId
+  )[0];
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use multisig_core::MultisigError;
 
 #[error_code]
 pub enum CustomError {
@@ -22,6 +23,12 @@ pub enum CustomError {
     MemberCheckFailed,
     #[msg("Invalid pending authority")]
     InvalidPendingAuthority,
+    #[msg("No pending authority")]
+    NoPendingAuthority,
+    #[msg("Invalid validity period")]
+    InvalidValidityPeriod,
+    #[msg("Nomination expired")]
+    NominationExpired,
     #[msg("Invalid factory program")]
     InvalidFactoryProgram,
     #[msg("Already initialized")]
@@ -36,4 +43,58 @@ pub enum CustomError {
     InvalidTokenAccountAddress,
     #[msg("Invalid program ID for PDA ownership")]
     InvalidProgramId,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Timelock enabled, the change must be queued")]
+    TimelockEnabled,
+    #[msg("Timelock is not enabled")]
+    TimelockDisabled,
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    #[msg("Timelock ETA is too early")]
+    TimelockEtaTooEarly,
+    #[msg("Timelock operation is not ready")]
+    TimelockNotReady,
+    #[msg("Timelock operation is stale")]
+    TimelockStale,
+    #[msg("Controller does not hold the mint authority")]
+    MintAuthorityNotHeld,
+    #[msg("Controller already holds the mint authority")]
+    MintAuthorityAlreadyHeld,
+    #[msg("Mint authority is not the controller")]
+    MintAuthorityNotController,
+    #[msg("Controller does not hold the freeze authority")]
+    FreezeAuthorityNotHeld,
+    #[msg("Invalid multisig signers")]
+    InvalidMultisigSigners,
+    #[msg("Invalid multisig threshold")]
+    InvalidMultisigThreshold,
+    #[msg("Not a multisig signer")]
+    NotMultisigSigner,
+    #[msg("Invalid proposal")]
+    InvalidProposal,
+    #[msg("Proposal already approved by signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal threshold not met")]
+    ProposalThresholdNotMet,
+    #[msg("Proposal is stale after a signer set change")]
+    StaleProposal,
+    #[msg("Token mint and Token-2022 program are required")]
+    MissingTokenAccounts,
+    #[msg("Controller store is already in the current layout")]
+    AlreadyMigrated,
+}
+
+impl From<MultisigError> for CustomError {
+    fn from(error: MultisigError) -> Self {
+        match error {
+            MultisigError::InvalidSigners => CustomError::InvalidMultisigSigners,
+            MultisigError::InvalidThreshold => CustomError::InvalidMultisigThreshold,
+            MultisigError::InvalidProposal => CustomError::InvalidProposal,
+            MultisigError::AlreadyApproved => CustomError::ProposalAlreadyApproved,
+            MultisigError::ThresholdNotMet => CustomError::ProposalThresholdNotMet,
+            MultisigError::StaleProposal => CustomError::StaleProposal,
+            MultisigError::MathOverflow => CustomError::MathOverflow,
+        }
+    }
 }
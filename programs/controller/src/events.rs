@@ -1,3 +1,5 @@
+use crate::instructions::MetadataField;
+use crate::state::TimelockAction;
 use anchor_lang::prelude::*;
 
 #[event]
@@ -14,6 +16,7 @@ pub struct FactorySet {
 pub struct OwnershipTransferStarted {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
+    pub deadline: i64,
 }
 
 #[event]
@@ -22,6 +25,12 @@ pub struct OwnershipTransferred {
     pub new_owner: Pubkey,
 }
 
+#[event]
+pub struct OwnershipTransferCanceled {
+    pub owner: Pubkey,
+    pub canceled_owner: Pubkey,
+}
+
 #[event]
 pub struct ControllerInitialized {
     pub token_mint: Pubkey,
@@ -32,6 +41,7 @@ pub struct ControllerInitialized {
 pub struct MintAuthorityTransferStarted {
     pub token_mint: Pubkey,
     pub new_authority: Pubkey,
+    pub deadline: i64,
 }
 
 #[event]
@@ -39,3 +49,174 @@ pub struct MintAuthorityTransferred {
     pub token_mint: Pubkey,
     pub new_authority: Pubkey,
 }
+
+#[event]
+pub struct MintAuthorityReclaimed {
+    pub token_mint: Pubkey,
+}
+
+#[event]
+pub struct MintAuthorityTransferCanceled {
+    pub token_mint: Pubkey,
+    pub canceled_authority: Pubkey,
+}
+
+#[event]
+pub struct FreezeAuthorityTransferStarted {
+    pub token_mint: Pubkey,
+    pub new_authority: Pubkey,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct FreezeAuthorityTransferred {
+    pub token_mint: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct FreezeAuthorityTransferCanceled {
+    pub token_mint: Pubkey,
+    pub canceled_authority: Pubkey,
+}
+
+#[event]
+pub struct FreezerSet {
+    pub freezer: Pubkey,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub account_owner: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub account_owner: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct Minted {
+    pub recipient: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub supply: u64,
+    pub factory: Pubkey,
+    pub mint_sequence: u64,
+    pub total_minted: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct TimelockConfigSet {
+    pub delay: i64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct TimelockOperationQueued {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub eta: i64,
+}
+
+#[event]
+pub struct TimelockOperationExecuted {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct TimelockOperationCanceled {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub canceled_by: Pubkey,
+}
+
+#[event]
+pub struct MultisigCreated {
+    pub authority: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigSignersSet {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub generation: u32,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub generation: u32,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub id: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalCanceled {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MetadataPointerUpdated {
+    pub token_mint: Pubkey,
+    pub metadata_address: Option<Pubkey>,
+}
+
+#[event]
+pub struct TokenMetadataInitialized {
+    pub token_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct TokenMetadataFieldUpdated {
+    pub token_mint: Pubkey,
+    pub field: MetadataField,
+    pub value: String,
+}
+
+#[event]
+pub struct TokenMetadataFieldRemoved {
+    pub token_mint: Pubkey,
+    pub key: String,
+}
+
+#[event]
+pub struct TransferHookSet {
+    pub token_mint: Pubkey,
+    pub program_id: Option<Pubkey>,
+}
+
+#[event]
+pub struct ControllerStoreMigrated {
+    pub owner: Pubkey,
+    pub mint_authority_held: bool,
+    pub total_minted: u64,
+}
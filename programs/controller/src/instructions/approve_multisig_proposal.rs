@@ -0,0 +1,44 @@
+use crate::errors::CustomError;
+use crate::events::MultisigProposalApproved;
+use crate::state::{Multisig, MultisigProposal};
+use crate::{MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ApproveMultisigProposal<'info> {
+    #[account(constraint = multisig.is_signer(&signer.key()) @ CustomError::NotMultisigSigner)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let signer = ctx.accounts.signer.key();
+    multisig_core::approve(
+        proposal.generation,
+        multisig.generation,
+        &mut proposal.approvals,
+        signer,
+    )
+    .map_err(CustomError::from)?;
+
+    emit!(MultisigProposalApproved {
+        id: proposal.id,
+        signer,
+        approvals: proposal.approvals.len() as u8,
+        threshold: multisig.threshold,
+    });
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::errors::CustomError;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use crate::FACTORY_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BurnParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: BurnParams)]
+pub struct _Burn<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump,
+        seeds::program = controller_store.factory
+    )]
+    pub factory_store: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    // holder of the tokens, its signature is forwarded by the factory
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn burn(ctx: Context<_Burn>, params: BurnParams) -> Result<()> {
+    let cpi_accounts = token_interface::BurnChecked {
+        mint: ctx.accounts.token_mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+    // Execute burn operation
+    token_interface::burn_checked(cpi_ctx, params.amount, ctx.accounts.token_mint.decimals)?;
+
+    // Update supply accounting
+    let controller_store = &mut ctx.accounts.controller_store;
+    controller_store.total_burned = controller_store
+        .total_burned
+        .checked_add(params.amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::errors::CustomError;
+use crate::events::MintAuthorityTransferCanceled;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelMintAuthorityTransfer<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+}
+
+pub fn cancel_mint_authority_transfer(ctx: Context<CancelMintAuthorityTransfer>) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        controller_store.pending_mint_authority != Pubkey::default(),
+        CustomError::NoPendingAuthority
+    );
+
+    let canceled_authority = controller_store.pending_mint_authority;
+    controller_store.pending_mint_authority = Pubkey::default();
+    controller_store.pending_mint_authority_deadline = 0;
+
+    emit!(MintAuthorityTransferCanceled {
+        token_mint: controller_store.token_mint,
+        canceled_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::errors::CustomError;
+use crate::events::MultisigProposalCanceled;
+use crate::state::MultisigProposal;
+use crate::MULTISIG_PROPOSAL_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelMultisigProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer @ CustomError::Unauthorized,
+        close = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+    emit!(MultisigProposalCanceled {
+        id: ctx.accounts.proposal.id,
+        proposer: ctx.accounts.proposer.key(),
+    });
+    Ok(())
+}
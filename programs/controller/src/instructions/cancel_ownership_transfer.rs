@@ -0,0 +1,36 @@
+use crate::errors::CustomError;
+use crate::events::OwnershipTransferCanceled;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    pub owner: Signer<'info>,
+}
+
+pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        controller_store.pending_owner != Pubkey::default(),
+        CustomError::NoPendingOwner
+    );
+
+    let canceled_owner = controller_store.pending_owner;
+    controller_store.pending_owner = Pubkey::default();
+    controller_store.pending_owner_deadline = 0;
+
+    emit!(OwnershipTransferCanceled {
+        owner: controller_store.owner,
+        canceled_owner,
+    });
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::errors::CustomError;
+use crate::events::TimelockOperationCanceled;
+use crate::state::TimelockOperation;
+use crate::ControllerStore;
+use crate::{CONTROLLER_SEED, TIMELOCK_OPERATION_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelTimelockOperation<'info> {
+    // Guardian or owner
+    #[account(
+        constraint = authority.key() == controller_store.guardian
+            || authority.key() == controller_store.owner @ CustomError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        seeds = [TIMELOCK_OPERATION_SEED, timelock_operation.id.to_le_bytes().as_ref()],
+        bump = timelock_operation.bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub timelock_operation: Account<'info, TimelockOperation>,
+    /// CHECK: receives the rent of the operation account
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn cancel_timelock_operation(ctx: Context<CancelTimelockOperation>) -> Result<()> {
+    let timelock_operation = &ctx.accounts.timelock_operation;
+    emit!(TimelockOperationCanceled {
+        id: timelock_operation.id,
+        action: timelock_operation.action.clone(),
+        canceled_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
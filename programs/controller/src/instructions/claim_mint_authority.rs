@@ -27,6 +27,10 @@ pub struct ClaimMintAuthority<'info> {
 
 pub fn claim_mint_authority(ctx: Context<ClaimMintAuthority>) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        Clock::get()?.unix_timestamp <= controller_store.pending_mint_authority_deadline,
+        CustomError::NominationExpired
+    );
     let pending_authority = controller_store.pending_mint_authority;
 
     // Get the seeds for PDA signing
@@ -51,6 +55,8 @@ pub fn claim_mint_authority(ctx: Context<ClaimMintAuthority>) -> Result<()> {
 
     // Reset pending mint authority
     controller_store.pending_mint_authority = Pubkey::default();
+    controller_store.pending_mint_authority_deadline = 0;
+    controller_store.mint_authority_held = false;
 
     emit!(MintAuthorityTransferred {
         token_mint: ctx.accounts.token_mint.key(),
@@ -18,11 +18,16 @@ pub struct ClaimOwnershipContext<'info> {
 
 pub fn claim_ownership(ctx: Context<ClaimOwnershipContext>) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        Clock::get()?.unix_timestamp <= controller_store.pending_owner_deadline,
+        CustomError::NominationExpired
+    );
     let pending_owner = controller_store.pending_owner;
 
     let previous_owner = controller_store.owner;
     controller_store.owner = pending_owner;
     controller_store.pending_owner = Pubkey::default();
+    controller_store.pending_owner_deadline = 0;
 
     emit!(OwnershipTransferred {
         previous_owner,
@@ -0,0 +1,61 @@
+use crate::errors::CustomError;
+use crate::events::MultisigProposalCreated;
+use crate::state::{Multisig, MultisigProposal};
+use crate::{MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
+use anchor_lang::prelude::*;
+
+pub use multisig_core::CreateMultisigProposalParams;
+
+#[derive(Accounts)]
+#[instruction(params: CreateMultisigProposalParams)]
+pub struct CreateMultisigProposal<'info> {
+    #[account(
+        mut,
+        constraint = multisig.is_signer(&proposer.key()) @ CustomError::NotMultisigSigner
+    )]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + MultisigProposal::INIT_SPACE,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+// The proposer's signature counts as the first approval
+pub fn create_multisig_proposal(
+    ctx: Context<CreateMultisigProposal>,
+    params: CreateMultisigProposalParams,
+) -> Result<()> {
+    multisig_core::validate_proposal(&params).map_err(CustomError::from)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    let id =
+        multisig_core::next_proposal_id(&mut multisig.proposal_count).map_err(CustomError::from)?;
+
+    let proposer = ctx.accounts.proposer.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = id;
+    proposal.proposer = proposer;
+    proposal.generation = multisig.generation;
+    proposal.accounts = params.accounts;
+    proposal.data = params.data;
+    proposal.approvals = vec![proposer];
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(MultisigProposalCreated {
+        id,
+        proposer,
+        generation: multisig.generation,
+    });
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use crate::errors::CustomError;
+use crate::events::{
+    FactorySet, FreezeAuthorityTransferStarted, MembersSet, MintAuthorityTransferStarted,
+    TimelockConfigSet, TimelockOperationExecuted,
+};
+use crate::instructions::set_transfer_hook::update_transfer_hook;
+use crate::state::{TimelockAction, TimelockOperation, TIMELOCK_GRACE_PERIOD};
+use crate::ControllerStore;
+use crate::{CONTROLLER_SEED, TIMELOCK_OPERATION_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ExecuteTimelockOperation<'info> {
+    // Permissionless, anyone can execute a ready operation
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        seeds = [TIMELOCK_OPERATION_SEED, timelock_operation.id.to_le_bytes().as_ref()],
+        bump = timelock_operation.bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub timelock_operation: Account<'info, TimelockOperation>,
+    /// CHECK: receives the rent of the operation account
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    // only required to execute a transfer hook change
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Program<'info, Token2022>>,
+}
+
+pub fn execute_timelock_operation(ctx: Context<ExecuteTimelockOperation>) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    let timelock_operation = &ctx.accounts.timelock_operation;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= timelock_operation.eta, CustomError::TimelockNotReady);
+    let expires_at = timelock_operation
+        .eta
+        .checked_add(TIMELOCK_GRACE_PERIOD)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(now <= expires_at, CustomError::TimelockStale);
+
+    match timelock_operation.action {
+        TimelockAction::SetFactory { factory } => {
+            controller_store.factory = factory;
+            emit!(FactorySet { factory });
+        }
+        TimelockAction::SetMembers { members } => {
+            controller_store.members = members;
+            emit!(MembersSet { members });
+        }
+        TimelockAction::TransferMintAuthority {
+            new_authority,
+            validity_period,
+        } => {
+            let deadline = now
+                .checked_add(validity_period)
+                .ok_or(CustomError::MathOverflow)?;
+            controller_store.pending_mint_authority = new_authority;
+            controller_store.pending_mint_authority_deadline = deadline;
+            emit!(MintAuthorityTransferStarted {
+                token_mint: controller_store.token_mint,
+                new_authority,
+                deadline,
+            });
+        }
+        TimelockAction::TransferFreezeAuthority {
+            new_authority,
+            validity_period,
+        } => {
+            let deadline = now
+                .checked_add(validity_period)
+                .ok_or(CustomError::MathOverflow)?;
+            controller_store.pending_freeze_authority = new_authority;
+            controller_store.pending_freeze_authority_deadline = deadline;
+            emit!(FreezeAuthorityTransferStarted {
+                token_mint: controller_store.token_mint,
+                new_authority,
+                deadline,
+            });
+        }
+        TimelockAction::SetTimelockConfig { delay, guardian } => {
+            controller_store.timelock_delay = delay;
+            controller_store.guardian = guardian;
+            emit!(TimelockConfigSet { delay, guardian });
+        }
+        TimelockAction::SetTransferHook { program_id } => {
+            let (Some(token_mint), Some(token_program)) =
+                (&ctx.accounts.token_mint, &ctx.accounts.token_program)
+            else {
+                return err!(CustomError::MissingTokenAccounts);
+            };
+            update_transfer_hook(controller_store, token_mint, token_program, program_id)?;
+        }
+    }
+
+    emit!(TimelockOperationExecuted {
+        id: timelock_operation.id,
+        action: timelock_operation.action.clone(),
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
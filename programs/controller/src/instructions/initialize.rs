@@ -3,6 +3,7 @@ use crate::events::ControllerInitialized;
 use crate::ControllerStore;
 use crate::CONTROLLER_SEED;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
@@ -39,6 +40,19 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     controller_store.owner = ctx.accounts.payer.key();
     controller_store.pending_owner = Pubkey::default();
     controller_store.pending_mint_authority = Pubkey::default();
+    controller_store.pending_owner_deadline = 0;
+    controller_store.pending_mint_authority_deadline = 0;
+    controller_store.total_minted = 0;
+    controller_store.total_burned = 0;
+    controller_store.mint_sequence = 0;
+    controller_store.guardian = Pubkey::default();
+    controller_store.timelock_delay = 0;
+    controller_store.timelock_operation_counter = 0;
+    controller_store.mint_authority_held =
+        ctx.accounts.token_mint.mint_authority == COption::Some(controller_store.key());
+    controller_store.freezer = Pubkey::default();
+    controller_store.pending_freeze_authority = Pubkey::default();
+    controller_store.pending_freeze_authority_deadline = 0;
     controller_store.bump = ctx.bumps.controller_store;
 
     emit!(ControllerInitialized {
@@ -0,0 +1,110 @@
+use crate::errors::CustomError;
+use crate::events::TokenMetadataInitialized;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::{token_metadata_initialize, TokenMetadataInitialize};
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeTokenMetadataParams {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeTokenMetadataParams)]
+pub struct InitializeTokenMetadata<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+// Metadata lives in the mint itself, its metadata pointer must point at the mint
+pub fn initialize_token_metadata(
+    ctx: Context<InitializeTokenMetadata>,
+    params: InitializeTokenMetadataParams,
+) -> Result<()> {
+    let controller_store = &ctx.accounts.controller_store;
+    require!(
+        controller_store.mint_authority_held,
+        CustomError::MintAuthorityNotHeld
+    );
+
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let mint = ctx.accounts.token_mint.to_account_info();
+    let cpi_accounts = TokenMetadataInitialize {
+        program_id: ctx.accounts.token_program.to_account_info(),
+        metadata: mint.clone(),
+        update_authority: controller_store.to_account_info(),
+        mint_authority: controller_store.to_account_info(),
+        mint: mint.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_metadata_initialize(
+        cpi_ctx,
+        params.name.clone(),
+        params.symbol.clone(),
+        params.uri.clone(),
+    )?;
+
+    top_up_mint_rent(
+        &ctx.accounts.owner.to_account_info(),
+        &mint,
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(TokenMetadataInitialized {
+        token_mint: mint.key(),
+        name: params.name,
+        symbol: params.symbol,
+        uri: params.uri,
+    });
+    Ok(())
+}
+
+// Token-2022 reallocates the mint for metadata, the payer covers the extra rent
+pub(crate) fn top_up_mint_rent<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(mint.data_len());
+    let missing = required.saturating_sub(mint.lamports());
+    if missing > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            missing,
+        )?;
+    }
+    Ok(())
+}
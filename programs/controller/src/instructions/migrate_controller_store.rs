@@ -0,0 +1,133 @@
+use crate::errors::CustomError;
+use crate::events::ControllerStoreMigrated;
+use crate::state::{ControllerStore, ControllerStoreV0};
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateControllerStoreParams {
+    pub pending_validity_period: i64, // seconds legacy nominations stay claimable, 0 drops them
+}
+
+// Rewrites a controller store created with the original layout into the current
+// one, the account is grown in place and the owner tops up its rent
+#[derive(Accounts)]
+#[instruction(params: MigrateControllerStoreParams)]
+pub struct MigrateControllerStore<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: still in the legacy layout, owner and discriminator are checked by the handler
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+        owner = crate::ID @ CustomError::InvalidProgramId
+    )]
+    pub controller_store: UncheckedAccount<'info>,
+    // checked against the legacy token_mint by the handler
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_controller_store(
+    ctx: Context<MigrateControllerStore>,
+    params: MigrateControllerStoreParams,
+) -> Result<()> {
+    let controller_store_info = ctx.accounts.controller_store.to_account_info();
+    require!(
+        controller_store_info.data_len() == 8 + ControllerStoreV0::INIT_SPACE,
+        CustomError::AlreadyMigrated
+    );
+    let legacy = {
+        let data = controller_store_info.try_borrow_data()?;
+        require!(
+            data[..8] == *ControllerStore::DISCRIMINATOR,
+            CustomError::AlreadyMigrated
+        );
+        ControllerStoreV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.owner, ctx.accounts.owner.key(), CustomError::Unauthorized);
+    require_keys_eq!(
+        legacy.token_mint,
+        ctx.accounts.token_mint.key(),
+        CustomError::TokenMintMismatch
+    );
+    require!(
+        params.pending_validity_period >= 0,
+        CustomError::InvalidValidityPeriod
+    );
+
+    // nominations made under the original layout had no deadline, they get one now or are dropped
+    let deadline = if params.pending_validity_period > 0 {
+        Clock::get()?
+            .unix_timestamp
+            .checked_add(params.pending_validity_period)
+            .ok_or(CustomError::MathOverflow)?
+    } else {
+        0
+    };
+    let nomination = |pending: Pubkey| {
+        if pending != Pubkey::default() && deadline > 0 {
+            (pending, deadline)
+        } else {
+            (Pubkey::default(), 0)
+        }
+    };
+    let (pending_owner, pending_owner_deadline) = nomination(legacy.pending_owner);
+    let (pending_mint_authority, pending_mint_authority_deadline) =
+        nomination(legacy.pending_mint_authority);
+
+    let token_mint = &ctx.accounts.token_mint;
+    let controller_store = ControllerStore {
+        bump: legacy.bump,
+        token_mint: legacy.token_mint,
+        members: legacy.members,
+        factory: legacy.factory,
+        owner: legacy.owner,
+        pending_owner,
+        pending_mint_authority,
+        pending_owner_deadline,
+        pending_mint_authority_deadline,
+        // everything minted so far is still outstanding as far as the counters know
+        total_minted: token_mint.supply,
+        total_burned: 0,
+        mint_sequence: 0,
+        guardian: Pubkey::default(),
+        timelock_delay: 0,
+        timelock_operation_counter: 0,
+        mint_authority_held: token_mint.mint_authority
+            == COption::Some(controller_store_info.key()),
+        freezer: Pubkey::default(),
+        pending_freeze_authority: Pubkey::default(),
+        pending_freeze_authority_deadline: 0,
+    };
+
+    let new_len = 8 + ControllerStore::INIT_SPACE;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(controller_store_info.lamports());
+    if rent_due > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: controller_store_info.clone(),
+            },
+        );
+        transfer(cpi_ctx, rent_due)?;
+    }
+    controller_store_info.realloc(new_len, false)?;
+    controller_store
+        .try_serialize(&mut &mut controller_store_info.try_borrow_mut_data()?[..])?;
+
+    emit!(ControllerStoreMigrated {
+        owner: controller_store.owner,
+        mint_authority_held: controller_store.mint_authority_held,
+        total_minted: controller_store.total_minted,
+    });
+    Ok(())
+}
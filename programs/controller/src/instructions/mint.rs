@@ -1,4 +1,5 @@
 use crate::errors::CustomError;
+use crate::events::Minted;
 use crate::ControllerStore;
 use crate::CONTROLLER_SEED;
 use crate::FACTORY_SEED;
@@ -23,6 +24,7 @@ pub struct _Mint<'info> {
     )]
     pub factory_store: Signer<'info>,
     #[account(
+        mut,
         seeds = [CONTROLLER_SEED],
         bump = controller_store.bump
     )]
@@ -46,6 +48,10 @@ pub struct _Mint<'info> {
 
 pub fn mint(ctx: Context<_Mint>, params: MintParams) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        controller_store.mint_authority_held,
+        CustomError::MintAuthorityNotHeld
+    );
     require!(
         params.to != Pubkey::default(),
         CustomError::InvalidToAddress
@@ -68,5 +74,29 @@ pub fn mint(ctx: Context<_Mint>, params: MintParams) -> Result<()> {
     // Execute mint operation
     token_interface::mint_to_checked(cpi_ctx, params.amount, ctx.accounts.token_mint.decimals)?;
 
+    // Update supply accounting
+    controller_store.total_minted = controller_store
+        .total_minted
+        .checked_add(params.amount)
+        .ok_or(CustomError::MathOverflow)?;
+    controller_store.mint_sequence = controller_store
+        .mint_sequence
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Read back the resulting supply
+    ctx.accounts.token_mint.reload()?;
+
+    emit!(Minted {
+        recipient: params.to,
+        token_account: ctx.accounts.token_account.key(),
+        amount: params.amount,
+        supply: ctx.accounts.token_mint.supply,
+        factory: controller_store.factory,
+        mint_sequence: controller_store.mint_sequence,
+        total_minted: controller_store.total_minted,
+        total_burned: controller_store.total_burned,
+    });
+
     Ok(())
 }
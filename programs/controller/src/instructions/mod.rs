@@ -1,19 +1,71 @@
 // Module declarations
+pub mod approve_multisig_proposal;
+pub mod burn;
+pub mod cancel_freeze_authority_transfer;
+pub mod cancel_mint_authority_transfer;
+pub mod cancel_multisig_proposal;
+pub mod cancel_ownership_transfer;
+pub mod cancel_timelock_operation;
+pub mod claim_freeze_authority;
 pub mod claim_mint_authority;
 pub mod claim_ownership;
+pub mod create_multisig;
+pub mod create_multisig_proposal;
+pub mod execute_multisig_proposal;
+pub mod execute_timelock_operation;
+pub mod freeze_account;
 pub mod initialize;
+pub mod initialize_token_metadata;
+pub mod migrate_controller_store;
 pub mod mint;
+pub mod queue_timelock_operation;
+pub mod reclaim_mint_authority;
+pub mod remove_token_metadata_field;
 pub mod set_factory;
+pub mod set_freezer;
 pub mod set_members;
+pub mod set_multisig_signers;
+pub mod set_timelock_config;
+pub mod set_transfer_hook;
+pub mod thaw_account;
+pub mod transfer_freeze_authority;
 pub mod transfer_mint_authority;
 pub mod transfer_ownership;
+pub mod update_metadata_pointer;
+pub mod update_token_metadata_field;
 
 // Re-export all instructions
+pub use approve_multisig_proposal::*;
+pub use burn::*;
+pub use cancel_freeze_authority_transfer::*;
+pub use cancel_mint_authority_transfer::*;
+pub use cancel_multisig_proposal::*;
+pub use cancel_ownership_transfer::*;
+pub use cancel_timelock_operation::*;
+pub use claim_freeze_authority::*;
 pub use claim_mint_authority::*;
 pub use claim_ownership::*;
+pub use create_multisig::*;
+pub use create_multisig_proposal::*;
+pub use execute_multisig_proposal::*;
+pub use execute_timelock_operation::*;
+pub use freeze_account::*;
 pub use initialize::*;
+pub use initialize_token_metadata::*;
+pub use migrate_controller_store::*;
 pub use mint::*;
+pub use queue_timelock_operation::*;
+pub use reclaim_mint_authority::*;
+pub use remove_token_metadata_field::*;
 pub use set_factory::*;
+pub use set_freezer::*;
 pub use set_members::*;
+pub use set_multisig_signers::*;
+pub use set_timelock_config::*;
+pub use set_transfer_hook::*;
+pub use thaw_account::*;
+pub use transfer_freeze_authority::*;
 pub use transfer_mint_authority::*;
 pub use transfer_ownership::*;
+pub use update_metadata_pointer::*;
+pub use update_token_metadata_field::*;
@@ -0,0 +1,75 @@
+use crate::errors::CustomError;
+use crate::events::TimelockOperationQueued;
+use crate::state::{TimelockAction, TimelockOperation};
+use crate::ControllerStore;
+use crate::{CONTROLLER_SEED, TIMELOCK_OPERATION_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QueueTimelockOperationParams {
+    pub action: TimelockAction,
+    pub eta: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: QueueTimelockOperationParams)]
+pub struct QueueTimelockOperation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + TimelockOperation::INIT_SPACE,
+        seeds = [
+            TIMELOCK_OPERATION_SEED,
+            controller_store.timelock_operation_counter.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub timelock_operation: Account<'info, TimelockOperation>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn queue_timelock_operation(
+    ctx: Context<QueueTimelockOperation>,
+    params: QueueTimelockOperationParams,
+) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        controller_store.is_timelocked(),
+        CustomError::TimelockDisabled
+    );
+    params.action.validate()?;
+
+    let earliest_eta = Clock::get()?
+        .unix_timestamp
+        .checked_add(controller_store.timelock_delay)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(params.eta >= earliest_eta, CustomError::TimelockEtaTooEarly);
+
+    let id = controller_store.timelock_operation_counter;
+    controller_store.timelock_operation_counter = id
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let timelock_operation = &mut ctx.accounts.timelock_operation;
+    timelock_operation.id = id;
+    timelock_operation.action = params.action.clone();
+    timelock_operation.eta = params.eta;
+    timelock_operation.proposer = ctx.accounts.owner.key();
+    timelock_operation.bump = ctx.bumps.timelock_operation;
+
+    emit!(TimelockOperationQueued {
+        id,
+        action: params.action,
+        eta: params.eta,
+    });
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::errors::CustomError;
+use crate::events::MintAuthorityReclaimed;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ReclaimMintAuthority<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Re-enables minting once the external authority has handed MintTokens back
+// to the controller PDA with a plain SPL set_authority
+pub fn reclaim_mint_authority(ctx: Context<ReclaimMintAuthority>) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.mint_authority_held,
+        CustomError::MintAuthorityAlreadyHeld
+    );
+    require!(
+        ctx.accounts.token_mint.mint_authority == COption::Some(controller_store.key()),
+        CustomError::MintAuthorityNotController
+    );
+
+    controller_store.mint_authority_held = true;
+
+    emit!(MintAuthorityReclaimed {
+        token_mint: ctx.accounts.token_mint.key(),
+    });
+    Ok(())
+}
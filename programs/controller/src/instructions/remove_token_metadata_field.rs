@@ -0,0 +1,68 @@
+use crate::errors::CustomError;
+use crate::events::TokenMetadataFieldRemoved;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::instruction::remove_key;
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveTokenMetadataFieldParams {
+    pub key: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RemoveTokenMetadataFieldParams)]
+pub struct RemoveTokenMetadataField<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn remove_token_metadata_field(
+    ctx: Context<RemoveTokenMetadataField>,
+    params: RemoveTokenMetadataFieldParams,
+) -> Result<()> {
+    let controller_store = &ctx.accounts.controller_store;
+
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let mint = ctx.accounts.token_mint.to_account_info();
+    let ix = remove_key(
+        ctx.accounts.token_program.key,
+        mint.key,
+        &controller_store.key(),
+        params.key.clone(),
+        false,
+    );
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.token_program.to_account_info(),
+            mint.clone(),
+            controller_store.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    emit!(TokenMetadataFieldRemoved {
+        token_mint: mint.key(),
+        key: params.key,
+    });
+    Ok(())
+}
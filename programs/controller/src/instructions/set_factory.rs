@@ -24,6 +24,10 @@ pub struct SetFactory<'info> {
 
 pub fn set_factory(ctx: Context<SetFactory>, params: SetFactoryParams) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.is_timelocked(),
+        CustomError::TimelockEnabled
+    );
     require!(
         params.factory != Pubkey::default(),
         CustomError::InvalidFactoryAddress
@@ -0,0 +1,32 @@
+use crate::errors::CustomError;
+use crate::events::FreezerSet;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFreezerParams {
+    pub freezer: Pubkey, // Pubkey::default() revokes the role
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetFreezerParams)]
+pub struct SetFreezer<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+}
+
+pub fn set_freezer(ctx: Context<SetFreezer>, params: SetFreezerParams) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    controller_store.freezer = params.freezer;
+    emit!(FreezerSet {
+        freezer: params.freezer
+    });
+    Ok(())
+}
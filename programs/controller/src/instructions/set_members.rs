@@ -24,6 +24,10 @@ pub struct SetMembers<'info> {
 
 pub fn set_members(ctx: Context<SetMembers>, params: SetMembersParams) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.is_timelocked(),
+        CustomError::TimelockEnabled
+    );
     require!(
         params.members != Pubkey::default(),
         CustomError::InvalidMembersAddress
@@ -0,0 +1,50 @@
+use crate::errors::CustomError;
+use crate::events::TimelockConfigSet;
+use crate::state::TIMELOCK_MAX_DELAY;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetTimelockConfigParams {
+    pub delay: i64,
+    pub guardian: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetTimelockConfigParams)]
+pub struct SetTimelockConfig<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+}
+
+// Enables the timelock, later changes must be queued
+pub fn set_timelock_config(
+    ctx: Context<SetTimelockConfig>,
+    params: SetTimelockConfigParams,
+) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.is_timelocked(),
+        CustomError::TimelockEnabled
+    );
+    require!(
+        (0..=TIMELOCK_MAX_DELAY).contains(&params.delay),
+        CustomError::InvalidTimelockDelay
+    );
+
+    controller_store.timelock_delay = params.delay;
+    controller_store.guardian = params.guardian;
+
+    emit!(TimelockConfigSet {
+        delay: params.delay,
+        guardian: params.guardian,
+    });
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::errors::CustomError;
+use crate::events::TransferHookSet;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::{transfer_hook_update, TransferHookUpdate};
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetTransferHookParams {
+    pub program_id: Option<Pubkey>, // None disables the hook
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetTransferHookParams)]
+pub struct SetTransferHook<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized,
+        // with the timelock enabled the change goes through execute_timelock_operation
+        constraint = !controller_store.is_timelocked() @ CustomError::TimelockEnabled
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn set_transfer_hook(
+    ctx: Context<SetTransferHook>,
+    params: SetTransferHookParams,
+) -> Result<()> {
+    update_transfer_hook(
+        &ctx.accounts.controller_store,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        params.program_id,
+    )
+}
+
+// The controller PDA must be the transfer hook authority, set when the mint was created
+pub(crate) fn update_transfer_hook<'info>(
+    controller_store: &Account<'info, ControllerStore>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Program<'info, Token2022>,
+    program_id: Option<Pubkey>,
+) -> Result<()> {
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let cpi_accounts = TransferHookUpdate {
+        token_program_id: token_program.to_account_info(),
+        mint: token_mint.to_account_info(),
+        authority: controller_store.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_hook_update(cpi_ctx, program_id)?;
+
+    emit!(TransferHookSet {
+        token_mint: token_mint.key(),
+        program_id,
+    });
+    Ok(())
+}
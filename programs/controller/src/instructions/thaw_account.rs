@@ -0,0 +1,60 @@
+use crate::errors::CustomError;
+use crate::events::AccountThawed;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ThawAccount<'info> {
+    // Owner or freezer
+    #[account(
+        constraint = controller_store.can_freeze(&authority.key()) @ CustomError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        address = controller_store.token_mint,
+        mint::token_program = token_program,
+        constraint = token_mint.freeze_authority == COption::Some(controller_store.key()) @ CustomError::FreezeAuthorityNotHeld
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+    let controller_store = &ctx.accounts.controller_store;
+
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let cpi_accounts = token_interface::ThawAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: controller_store.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token_interface::thaw_account(cpi_ctx)?;
+
+    emit!(AccountThawed {
+        token_mint: ctx.accounts.token_mint.key(),
+        token_account: ctx.accounts.token_account.key(),
+        account_owner: ctx.accounts.token_account.owner,
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::errors::CustomError;
+use crate::events::FreezeAuthorityTransferStarted;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferFreezeAuthorityParams {
+    pub new_authority: Pubkey,
+    pub validity_period: i64, // seconds the nomination can be claimed for
+}
+
+#[derive(Accounts)]
+#[instruction(params: TransferFreezeAuthorityParams)]
+pub struct TransferFreezeAuthority<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn transfer_freeze_authority(
+    ctx: Context<TransferFreezeAuthority>,
+    params: TransferFreezeAuthorityParams,
+) -> Result<()> {
+    let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.is_timelocked(),
+        CustomError::TimelockEnabled
+    );
+    require!(
+        params.new_authority != Pubkey::default(),
+        CustomError::InvalidPendingAuthority
+    );
+
+    require!(
+        params.validity_period > 0,
+        CustomError::InvalidValidityPeriod
+    );
+    let deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(params.validity_period)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Set pending freeze authority
+    controller_store.pending_freeze_authority = params.new_authority;
+    controller_store.pending_freeze_authority_deadline = deadline;
+
+    emit!(FreezeAuthorityTransferStarted {
+        token_mint: ctx.accounts.token_mint.key(),
+        new_authority: params.new_authority,
+        deadline,
+    });
+
+    Ok(())
+}
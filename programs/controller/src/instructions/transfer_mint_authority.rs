@@ -8,6 +8,7 @@ use anchor_spl::token_interface::{Mint, TokenInterface};
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TransferMintAuthorityParams {
     pub new_authority: Pubkey,
+    pub validity_period: i64, // seconds the nomination can be claimed for
 }
 
 #[derive(Accounts)]
@@ -34,17 +35,29 @@ pub fn transfer_mint_authority(
     params: TransferMintAuthorityParams,
 ) -> Result<()> {
     let controller_store = &mut ctx.accounts.controller_store;
+    require!(
+        !controller_store.is_timelocked(),
+        CustomError::TimelockEnabled
+    );
     require!(
         params.new_authority != Pubkey::default(),
         CustomError::InvalidPendingAuthority
     );
 
+    require!(params.validity_period > 0, CustomError::InvalidValidityPeriod);
+    let deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(params.validity_period)
+        .ok_or(CustomError::MathOverflow)?;
+
     // Set pending mint authority
     controller_store.pending_mint_authority = params.new_authority;
+    controller_store.pending_mint_authority_deadline = deadline;
 
     emit!(MintAuthorityTransferStarted {
         token_mint: ctx.accounts.token_mint.key(),
         new_authority: params.new_authority,
+        deadline,
     });
 
     Ok(())
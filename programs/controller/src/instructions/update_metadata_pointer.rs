@@ -0,0 +1,69 @@
+use crate::errors::CustomError;
+use crate::events::MetadataPointerUpdated;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::spl_token_2022::extension::metadata_pointer::instruction::update;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateMetadataPointerParams {
+    pub metadata_address: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateMetadataPointerParams)]
+pub struct UpdateMetadataPointer<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+// The controller PDA must be the metadata pointer authority, set when the mint was created
+pub fn update_metadata_pointer(
+    ctx: Context<UpdateMetadataPointer>,
+    params: UpdateMetadataPointerParams,
+) -> Result<()> {
+    let controller_store = &ctx.accounts.controller_store;
+
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let mint = ctx.accounts.token_mint.to_account_info();
+    let ix = update(
+        ctx.accounts.token_program.key,
+        mint.key,
+        &controller_store.key(),
+        &[],
+        params.metadata_address,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.token_program.to_account_info(),
+            mint.clone(),
+            controller_store.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    emit!(MetadataPointerUpdated {
+        token_mint: mint.key(),
+        metadata_address: params.metadata_address,
+    });
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::errors::CustomError;
+use crate::events::TokenMetadataFieldUpdated;
+use crate::instructions::top_up_mint_rent;
+use crate::ControllerStore;
+use crate::CONTROLLER_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::Field;
+use anchor_spl::token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField};
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MetadataField {
+    Name,
+    Symbol,
+    Uri,
+    Key(String), // additional field
+}
+
+impl From<MetadataField> for Field {
+    fn from(field: MetadataField) -> Self {
+        match field {
+            MetadataField::Name => Field::Name,
+            MetadataField::Symbol => Field::Symbol,
+            MetadataField::Uri => Field::Uri,
+            MetadataField::Key(key) => Field::Key(key),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateTokenMetadataFieldParams {
+    pub field: MetadataField,
+    pub value: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateTokenMetadataFieldParams)]
+pub struct UpdateTokenMetadataField<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_token_metadata_field(
+    ctx: Context<UpdateTokenMetadataField>,
+    params: UpdateTokenMetadataFieldParams,
+) -> Result<()> {
+    let controller_store = &ctx.accounts.controller_store;
+
+    // Get the seeds for PDA signing
+    let controller_seeds = &[CONTROLLER_SEED, &[controller_store.bump]];
+    let signer_seeds = &[&controller_seeds[..]];
+
+    let mint = ctx.accounts.token_mint.to_account_info();
+    let cpi_accounts = TokenMetadataUpdateField {
+        program_id: ctx.accounts.token_program.to_account_info(),
+        metadata: mint.clone(),
+        update_authority: controller_store.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_metadata_update_field(cpi_ctx, params.field.clone().into(), params.value.clone())?;
+
+    top_up_mint_rent(
+        &ctx.accounts.owner.to_account_info(),
+        &mint,
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(TokenMetadataFieldUpdated {
+        token_mint: mint.key(),
+        field: params.field,
+        value: params.value,
+    });
+    Ok(())
+}
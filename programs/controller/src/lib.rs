@@ -17,6 +17,8 @@ declare_id!("AtgYY2Aa91CGpuX9nX8U4ox7RevHD7N54LSJoDZzBxtE");
 // Seeds constants
 pub const CONTROLLER_SEED: &[u8] = b"controller";
 pub const FACTORY_SEED: &[u8] = b"factory";
+pub const TIMELOCK_OPERATION_SEED: &[u8] = b"timelock_operation";
+pub use multisig_core::{MULTISIG_AUTHORITY_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
 
 #[program]
 pub mod controller {
@@ -26,6 +28,13 @@ pub mod controller {
         instructions::initialize::initialize(ctx)
     }
 
+    pub fn migrate_controller_store(
+        ctx: Context<MigrateControllerStore>,
+        params: MigrateControllerStoreParams,
+    ) -> Result<()> {
+        instructions::migrate_controller_store::migrate_controller_store(ctx, params)
+    }
+
     pub fn set_members(ctx: Context<SetMembers>, params: SetMembersParams) -> Result<()> {
         instructions::set_members::set_members(ctx, params)
     }
@@ -34,10 +43,100 @@ pub mod controller {
         instructions::set_factory::set_factory(ctx, params)
     }
 
+    pub fn set_freezer(ctx: Context<SetFreezer>, params: SetFreezerParams) -> Result<()> {
+        instructions::set_freezer::set_freezer(ctx, params)
+    }
+
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        instructions::freeze_account::freeze_account(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        instructions::thaw_account::thaw_account(ctx)
+    }
+
+    pub fn transfer_freeze_authority(
+        ctx: Context<TransferFreezeAuthority>,
+        params: TransferFreezeAuthorityParams,
+    ) -> Result<()> {
+        instructions::transfer_freeze_authority::transfer_freeze_authority(ctx, params)
+    }
+
+    pub fn claim_freeze_authority(ctx: Context<ClaimFreezeAuthority>) -> Result<()> {
+        instructions::claim_freeze_authority::claim_freeze_authority(ctx)
+    }
+
+    pub fn cancel_freeze_authority_transfer(
+        ctx: Context<CancelFreezeAuthorityTransfer>,
+    ) -> Result<()> {
+        instructions::cancel_freeze_authority_transfer::cancel_freeze_authority_transfer(ctx)
+    }
+
+    pub fn update_metadata_pointer(
+        ctx: Context<UpdateMetadataPointer>,
+        params: UpdateMetadataPointerParams,
+    ) -> Result<()> {
+        instructions::update_metadata_pointer::update_metadata_pointer(ctx, params)
+    }
+
+    pub fn initialize_token_metadata(
+        ctx: Context<InitializeTokenMetadata>,
+        params: InitializeTokenMetadataParams,
+    ) -> Result<()> {
+        instructions::initialize_token_metadata::initialize_token_metadata(ctx, params)
+    }
+
+    pub fn update_token_metadata_field(
+        ctx: Context<UpdateTokenMetadataField>,
+        params: UpdateTokenMetadataFieldParams,
+    ) -> Result<()> {
+        instructions::update_token_metadata_field::update_token_metadata_field(ctx, params)
+    }
+
+    pub fn remove_token_metadata_field(
+        ctx: Context<RemoveTokenMetadataField>,
+        params: RemoveTokenMetadataFieldParams,
+    ) -> Result<()> {
+        instructions::remove_token_metadata_field::remove_token_metadata_field(ctx, params)
+    }
+
+    pub fn set_transfer_hook(
+        ctx: Context<SetTransferHook>,
+        params: SetTransferHookParams,
+    ) -> Result<()> {
+        instructions::set_transfer_hook::set_transfer_hook(ctx, params)
+    }
+
+    pub fn set_timelock_config(
+        ctx: Context<SetTimelockConfig>,
+        params: SetTimelockConfigParams,
+    ) -> Result<()> {
+        instructions::set_timelock_config::set_timelock_config(ctx, params)
+    }
+
+    pub fn queue_timelock_operation(
+        ctx: Context<QueueTimelockOperation>,
+        params: QueueTimelockOperationParams,
+    ) -> Result<()> {
+        instructions::queue_timelock_operation::queue_timelock_operation(ctx, params)
+    }
+
+    pub fn execute_timelock_operation(ctx: Context<ExecuteTimelockOperation>) -> Result<()> {
+        instructions::execute_timelock_operation::execute_timelock_operation(ctx)
+    }
+
+    pub fn cancel_timelock_operation(ctx: Context<CancelTimelockOperation>) -> Result<()> {
+        instructions::cancel_timelock_operation::cancel_timelock_operation(ctx)
+    }
+
     pub fn mint(ctx: Context<_Mint>, params: MintParams) -> Result<()> {
         instructions::mint::mint(ctx, params)
     }
 
+    pub fn burn(ctx: Context<_Burn>, params: BurnParams) -> Result<()> {
+        instructions::burn::burn(ctx, params)
+    }
+
     pub fn transfer_ownership(
         ctx: Context<SetOwnerContext>,
         params: TransferOwnershipParams,
@@ -49,6 +148,10 @@ pub mod controller {
         instructions::claim_ownership::claim_ownership(ctx)
     }
 
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer::cancel_ownership_transfer(ctx)
+    }
+
     pub fn transfer_mint_authority(
         ctx: Context<TransferMintAuthority>,
         params: TransferMintAuthorityParams,
@@ -59,4 +162,44 @@ pub mod controller {
     pub fn claim_mint_authority(ctx: Context<ClaimMintAuthority>) -> Result<()> {
         instructions::claim_mint_authority::claim_mint_authority(ctx)
     }
+
+    pub fn reclaim_mint_authority(ctx: Context<ReclaimMintAuthority>) -> Result<()> {
+        instructions::reclaim_mint_authority::reclaim_mint_authority(ctx)
+    }
+
+    pub fn cancel_mint_authority_transfer(ctx: Context<CancelMintAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_mint_authority_transfer::cancel_mint_authority_transfer(ctx)
+    }
+
+    pub fn create_multisig(ctx: Context<CreateMultisig>, params: MultisigSignersParams) -> Result<()> {
+        instructions::create_multisig::create_multisig(ctx, params)
+    }
+
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        params: MultisigSignersParams,
+    ) -> Result<()> {
+        instructions::set_multisig_signers::set_multisig_signers(ctx, params)
+    }
+
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        params: CreateMultisigProposalParams,
+    ) -> Result<()> {
+        instructions::create_multisig_proposal::create_multisig_proposal(ctx, params)
+    }
+
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        instructions::approve_multisig_proposal::approve_multisig_proposal(ctx)
+    }
+
+    pub fn execute_multisig_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_multisig_proposal::execute_multisig_proposal(ctx)
+    }
+
+    pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+        instructions::cancel_multisig_proposal::cancel_multisig_proposal(ctx)
+    }
 }
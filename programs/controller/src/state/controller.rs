@@ -10,4 +10,46 @@ pub struct ControllerStore {
     pub owner: Pubkey,
     pub pending_owner: Pubkey,
     pub pending_mint_authority: Pubkey,
+    // Nominations can only be claimed until their deadline (unix timestamp)
+    pub pending_owner_deadline: i64,
+    pub pending_mint_authority_deadline: i64,
+    // Monotonic supply accounting, reconciled off-chain against token_mint.supply
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub mint_sequence: u64,
+    // Timelocked governance, disabled while timelock_delay is 0
+    pub guardian: Pubkey,
+    pub timelock_delay: i64,
+    pub timelock_operation_counter: u64,
+    // Whether the controller PDA is the mint's MintTokens authority
+    pub mint_authority_held: bool,
+    // Freeze authority handover and the role allowed to freeze/thaw besides the owner
+    pub freezer: Pubkey,
+    pub pending_freeze_authority: Pubkey,
+    pub pending_freeze_authority_deadline: i64,
+}
+
+impl ControllerStore {
+    // Configuration changes must be queued once a delay is set
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    pub fn can_freeze(&self, key: &Pubkey) -> bool {
+        *key == self.owner || (self.freezer != Pubkey::default() && *key == self.freezer)
+    }
+}
+
+// Layout deployed before supply accounting, nomination deadlines, the timelock,
+// mint authority tracking and the freeze authority were added, read by
+// migrate_controller_store
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug)]
+pub struct ControllerStoreV0 {
+    pub bump: u8,
+    pub token_mint: Pubkey,
+    pub members: Pubkey,
+    pub factory: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub pending_mint_authority: Pubkey,
 }
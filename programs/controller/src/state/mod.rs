@@ -1,3 +1,7 @@
 pub mod controller;
+pub mod multisig;
+pub mod timelock;
 
-pub use controller::*; 
\ No newline at end of file
+pub use controller::*;
+pub use multisig::*;
+pub use timelock::*;
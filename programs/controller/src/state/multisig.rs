@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+pub use multisig_core::{
+    ProposalAccountMeta, MAX_MULTISIG_SIGNERS, MAX_PROPOSAL_ACCOUNTS, MAX_PROPOSAL_DATA_LEN,
+};
+
+#[derive(InitSpace, Debug)]
+#[account]
+pub struct Multisig {
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub generation: u32, // bumped on signer set changes, invalidating open proposals
+    pub authority_bump: u8, // bump of the authority PDA acting as owner
+    pub bump: u8,
+}
+
+#[derive(InitSpace, Debug)]
+#[account]
+pub struct MultisigProposal {
+    pub id: u64,
+    pub proposer: Pubkey, // receives the rent back once executed or canceled
+    pub generation: u32,
+    #[max_len(MAX_PROPOSAL_ACCOUNTS)]
+    pub accounts: Vec<ProposalAccountMeta>,
+    #[max_len(MAX_PROPOSAL_DATA_LEN)]
+    pub data: Vec<u8>,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::errors::CustomError;
+use anchor_lang::prelude::*;
+
+// Operations past eta + grace period can no longer be executed
+pub const TIMELOCK_GRACE_PERIOD: i64 = 14 * 24 * 60 * 60;
+pub const TIMELOCK_MAX_DELAY: i64 = 30 * 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Debug)]
+pub enum TimelockAction {
+    SetFactory {
+        factory: Pubkey,
+    },
+    SetMembers {
+        members: Pubkey,
+    },
+    TransferMintAuthority {
+        new_authority: Pubkey,
+        validity_period: i64,
+    },
+    TransferFreezeAuthority {
+        new_authority: Pubkey,
+        validity_period: i64,
+    },
+    SetTimelockConfig {
+        delay: i64,
+        guardian: Pubkey,
+    },
+    SetTransferHook {
+        program_id: Option<Pubkey>,
+    },
+}
+
+#[derive(InitSpace, Debug)]
+#[account]
+pub struct TimelockOperation {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub eta: i64,
+    pub proposer: Pubkey, // receives the rent back once executed or canceled
+    pub bump: u8,
+}
+
+impl TimelockAction {
+    // Checked when queued so execution only has to apply the change
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            TimelockAction::SetFactory { factory } => require!(
+                *factory != Pubkey::default(),
+                CustomError::InvalidFactoryAddress
+            ),
+            TimelockAction::SetMembers { members } => require!(
+                *members != Pubkey::default(),
+                CustomError::InvalidMembersAddress
+            ),
+            TimelockAction::TransferMintAuthority {
+                new_authority,
+                validity_period,
+            }
+            | TimelockAction::TransferFreezeAuthority {
+                new_authority,
+                validity_period,
+            } => {
+                require!(
+                    *new_authority != Pubkey::default(),
+                    CustomError::InvalidPendingAuthority
+                );
+                require!(*validity_period > 0, CustomError::InvalidValidityPeriod);
+            }
+            TimelockAction::SetTimelockConfig { delay, .. } => require!(
+                (0..=TIMELOCK_MAX_DELAY).contains(delay),
+                CustomError::InvalidTimelockDelay
+            ),
+            // None disables the hook
+            TimelockAction::SetTransferHook { .. } => {}
+        }
+        Ok(())
+    }
+}
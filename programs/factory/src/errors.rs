@@ -54,4 +54,74 @@ pub enum FactoryError {
     InvalidTransactionCharacters,
     #[msg("the transaction length is invalid (not 64 characters)")]
     InvalidTransactionLength,
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+    #[msg("Merchant still has pending requests.")]
+    PendingRequestsExist,
+    #[msg("Merchant compliance has expired.")]
+    MerchantComplianceExpired,
+    #[msg("Invalid merchant tier.")]
+    InvalidMerchantTier,
+    #[msg("Burns are not allowed for the merchant tier.")]
+    BurnsNotAllowed,
+    #[msg("Request amount exceeds the merchant tier limit.")]
+    TierRequestLimitExceeded,
+    #[msg("Daily cap of the merchant tier exceeded.")]
+    TierDailyCapExceeded,
+    #[msg("Address is denylisted.")]
+    AddressDenylisted,
+    #[msg("Invalid fee configuration.")]
+    InvalidFeeConfig,
+    #[msg("Fee recipient must be an associated token account of the token mint.")]
+    InvalidFeeRecipient,
+    #[msg("Fee recipient token account is required.")]
+    MissingFeeRecipient,
+    #[msg("Fee exceeds the request amount.")]
+    FeeExceedsAmount,
+    #[msg("BTC amount sent and network fee exceed the burned amount.")]
+    InvalidBtcPayout,
+    #[msg("Invalid amount adjustment reason.")]
+    InvalidAdjustmentReason,
+    #[msg("Too many payouts recorded for the burn request.")]
+    TooManyPayouts,
+    #[msg("Batch is empty.")]
+    EmptyBatch,
+    #[msg("Remaining accounts do not match the batch.")]
+    InvalidBatchAccounts,
+    #[msg("Mint recipient is not on the merchant allowlist.")]
+    RecipientNotAllowed,
+    #[msg("Mint recipient is already on the merchant allowlist.")]
+    RecipientAlreadyAllowed,
+    #[msg("Mint recipient allowlist is full.")]
+    TooManyRecipients,
+    #[msg("BTC payout address is not on the merchant allowlist.")]
+    BtcPayoutAddressNotAllowed,
+    #[msg("BTC payout address is already on the merchant allowlist.")]
+    BtcPayoutAddressAlreadyAllowed,
+    #[msg("BTC payout address allowlist is full.")]
+    TooManyBtcPayoutAddresses,
+    #[msg("BTC deposit address was not active at the time of deposit.")]
+    BtcDepositAddressNotActive,
+    #[msg("BTC deposit address is already retired.")]
+    BtcDepositAddressRetired,
+    #[msg("Invalid BTC deposit timestamp.")]
+    InvalidDepositTimestamp,
+    #[msg("No fee configuration change is pending.")]
+    NoPendingFeeConfig,
+    #[msg("Fee configuration change is still timelocked.")]
+    FeeConfigNotReady,
+    #[msg("BTC transaction output is assigned to several burns.")]
+    DuplicateBtcVout,
+    #[msg("BTC payout is already recorded for the burn request.")]
+    DuplicateBtcPayout,
+    #[msg("Burn payouts account is required once partial payouts are recorded.")]
+    MissingBurnPayouts,
+    #[msg("Burn with partial payouts must be confirmed on its own.")]
+    PartialPayoutsRecorded,
+    #[msg("Not a custodian BTC deposit address in the original layout.")]
+    NotLegacyBtcDepositAddress,
+    #[msg("Factory store is already in the current layout.")]
+    AlreadyMigrated,
+    #[msg("Request is already in the current layout.")]
+    RequestAlreadyMigrated,
 }
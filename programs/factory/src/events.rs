@@ -1,10 +1,22 @@
 use anchor_lang::prelude::*;
+use crate::state::RequestType;
 
 #[event]
 pub struct CustodianBtcDepositAddressSet {
     pub merchant: Pubkey,
     pub custodian: Pubkey,
+    pub index: u32,
     pub btc_deposit_address: String,
+    pub activated_at: i64,
+}
+
+#[event]
+pub struct CustodianBtcDepositAddressRetired {
+    pub merchant: Pubkey,
+    pub custodian: Pubkey,
+    pub index: u32,
+    pub btc_deposit_address: String,
+    pub retired_at: i64,
 }
 
 #[event]
@@ -17,8 +29,10 @@ pub struct MerchantBtcDepositAddressSet {
 pub struct MintRequestAdd {
     pub nonce: u64,
     pub requester: Pubkey,
+    pub recipient: Pubkey,
     pub amount: u64,
     pub btc_deposit_address: String,
+    pub btc_deposit_timestamp: i64,
     pub btc_txid: String,
     pub timestamp: i64,
     pub request_hash: [u8; 32],
@@ -35,11 +49,36 @@ pub struct MintRequestCancel {
 pub struct MintConfirmed {
     pub nonce: u64,
     pub requester: Pubkey,
+    pub recipient: Pubkey,
     pub amount: u64,
     pub btc_deposit_address: String,
     pub btc_txid: String,
     pub timestamp: i64,
     pub request_hash: [u8; 32],
+    pub fee: u64,
+    pub mint_sequence: u64,     // controller sequence of the mint to the recipient
+    pub fee_mint_sequence: u64, // controller sequence of the fee mint, 0 when no fee was charged
+    pub total_minted: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct MintPartiallyConfirmed {
+    pub nonce: u64,
+    pub requester: Pubkey,
+    pub recipient: Pubkey,
+    pub requested_amount: u64,
+    pub confirmed_amount: u64,
+    pub reason: String,
+    pub btc_deposit_address: String,
+    pub btc_txid: String,
+    pub timestamp: i64,
+    pub request_hash: [u8; 32],
+    pub fee: u64,
+    pub mint_sequence: u64,     // controller sequence of the mint to the recipient
+    pub fee_mint_sequence: u64, // controller sequence of the fee mint, 0 when no fee was charged
+    pub total_minted: u64,
+    pub total_burned: u64,
 }
 
 #[event]
@@ -61,6 +100,9 @@ pub struct Burned {
     pub btc_deposit_address: String,
     pub timestamp: i64,
     pub request_hash: [u8; 32],
+    pub fee: u64,
+    pub total_minted: u64,
+    pub total_burned: u64,
 }
 
 #[event]
@@ -70,6 +112,87 @@ pub struct BurnConfirmed {
     pub amount: u64,
     pub btc_deposit_address: String,
     pub btc_txid: String,
+    pub btc_vout: Option<u32>,
+    pub btc_amount_sent: u64,
+    pub btc_network_fee: u64,
     pub timestamp: i64,
     pub request_hash: [u8; 32],
 } 
+
+#[event]
+pub struct BurnPartiallySettled {
+    pub nonce: u64,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub btc_deposit_address: String,
+    pub btc_txid: String,
+    pub btc_amount_sent: u64,
+    pub btc_network_fee: u64,
+    pub total_btc_amount_sent: u64,
+    pub total_btc_network_fee: u64,
+    pub timestamp: i64,
+    pub request_hash: [u8; 32],
+}
+
+#[event]
+pub struct FeeConfigSet {
+    pub mint_fee_bps: u16,
+    pub burn_fee_bps: u16,
+    pub min_fee: u64,
+    pub fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct FeeConfigQueued {
+    pub mint_fee_bps: u16,
+    pub burn_fee_bps: u16,
+    pub min_fee: u64,
+    pub fee_recipient: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct FeeConfigCanceled {
+    pub canceled_by: Pubkey,
+}
+
+#[event]
+pub struct MintRecipientAdded {
+    pub merchant: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct MintRecipientRemoved {
+    pub merchant: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct BtcPayoutAddressAdded {
+    pub merchant: Pubkey,
+    pub btc_address: String,
+}
+
+#[event]
+pub struct BtcPayoutAddressRemoved {
+    pub merchant: Pubkey,
+    pub btc_address: String,
+}
+
+#[event]
+pub struct FactoryStoreMigrated {
+    pub controller: Pubkey,
+    pub mint_request_counter: u64,
+    pub burn_request_counter: u64,
+}
+
+#[event]
+pub struct RequestMigrated {
+    pub nonce: u64,
+    pub request_type: RequestType,
+    pub requester: Pubkey,
+    pub fee_bps: u16,
+    pub previous_request_hash: [u8; 32],
+    pub request_hash: [u8; 32],
+}
@@ -0,0 +1,330 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use controller::program::Controller as ControllerProgram;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+use members::MembersStore;
+use members::MEMBERS_SEED;
+use members::{DenylistEntry, DENYLIST_SEED};
+use crate::errors::FactoryError;
+use crate::events::{MintConfirmed, MintRejected};
+use crate::instructions::burn::approve_burn_request;
+use crate::instructions::mint_requests::ControllerMint;
+use crate::state::{FactoryStore, MerchantRequests, RequestAccount, RequestStatus, RequestType};
+use crate::BURN_REQUEST_SEED;
+use crate::FACTORY_SEED;
+use crate::MINT_REQUEST_SEED;
+
+// Remaining accounts per request: request PDA, merchant requests PDA,
+// recipient ATA and recipient denylist PDA
+const CONFIRM_ACCOUNTS_PER_REQUEST: usize = 4;
+// Remaining accounts per request: request PDA and merchant requests PDA
+const SETTLE_ACCOUNTS_PER_REQUEST: usize = 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintRequestRef {
+    pub nonce: u64,
+    pub request_hash: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchMintParams {
+    pub requests: Vec<MintRequestRef>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BurnRequestRef {
+    pub nonce: u64,
+    pub request_hash: [u8; 32],
+    pub vout: u32,
+    pub btc_amount_sent: u64,
+    pub btc_network_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchBurnParams {
+    pub btc_txid: String,
+    pub requests: Vec<BurnRequestRef>,
+}
+
+// Load a request passed as a remaining account, with the same checks the
+// single-request instructions apply through their constraints
+fn load_pending_request<'info>(
+    info: &'info AccountInfo<'info>,
+    request_type: RequestType,
+    nonce: u64,
+    request_hash: &[u8; 32],
+) -> Result<Account<'info, RequestAccount>> {
+    require!(info.is_writable, FactoryError::InvalidBatchAccounts);
+    let request = Account::<RequestAccount>::try_from(info)?;
+    let seed = match request_type {
+        RequestType::Mint => MINT_REQUEST_SEED,
+        RequestType::Burn => BURN_REQUEST_SEED,
+    };
+    let expected = Pubkey::create_program_address(
+        &[seed, &nonce.to_le_bytes(), &[request.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| FactoryError::InvalidNonce)?;
+    require_keys_eq!(info.key(), expected, FactoryError::InvalidNonce);
+    require!(request.request_type == request_type, FactoryError::InvalidRequestType);
+    require!(request.nonce == nonce, FactoryError::InvalidNonce);
+    require!(request.status == RequestStatus::Pending, FactoryError::NotPendingRequest);
+    require!(request.hash == *request_hash, FactoryError::MismatchRequestHash);
+    Ok(request)
+}
+
+fn load_merchant_requests<'info>(
+    info: &'info AccountInfo<'info>,
+    requester: &Pubkey,
+) -> Result<Account<'info, MerchantRequests>> {
+    require!(info.is_writable, FactoryError::InvalidBatchAccounts);
+    let merchant_requests = Account::<MerchantRequests>::try_from(info)?;
+    require_keys_eq!(merchant_requests.merchant, *requester, FactoryError::InvalidMerchant);
+    Ok(merchant_requests)
+}
+
+// ---- Confirm Mint Requests ----
+
+#[derive(Accounts)]
+pub struct ConfirmMintRequests<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    // Treasury token account, only required when a fee is charged
+    #[account(
+        mut,
+        address = factory_store.fee_recipient @ FactoryError::InvalidFeeRecipient,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = controller::ID)]
+    pub controller_program: Program<'info, ControllerProgram>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn confirm_mint_requests_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConfirmMintRequests<'info>>,
+    params: BatchMintParams,
+) -> Result<()> {
+    require!(!params.requests.is_empty(), FactoryError::EmptyBatch);
+    require!(
+        ctx.remaining_accounts.len() == params.requests.len() * CONFIRM_ACCOUNTS_PER_REQUEST,
+        FactoryError::InvalidBatchAccounts
+    );
+
+    for (entry, accounts) in params
+        .requests
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(CONFIRM_ACCOUNTS_PER_REQUEST))
+    {
+        let [request_info, merchant_requests_info, token_account, denylist_entry] = accounts else {
+            return err!(FactoryError::InvalidBatchAccounts);
+        };
+        let mut request = load_pending_request(request_info, RequestType::Mint, entry.nonce, &entry.request_hash)?;
+        let mut merchant_requests = load_merchant_requests(merchant_requests_info, &request.requester)?;
+
+        // the recipient must not be denylisted
+        let (expected_denylist_entry, _) = Pubkey::find_program_address(
+            &[DENYLIST_SEED, request.recipient.as_ref()],
+            &ctx.accounts.controller_store.members,
+        );
+        require_keys_eq!(denylist_entry.key(), expected_denylist_entry, FactoryError::InvalidToAddress);
+        require!(!DenylistEntry::is_denylisted(denylist_entry), FactoryError::AddressDenylisted);
+
+        // the controller checks the token account is the recipient's ATA
+        let minter = ControllerMint {
+            controller_program: ctx.accounts.controller_program.to_account_info(),
+            factory_store: &ctx.accounts.factory_store,
+            controller_store: ctx.accounts.controller_store.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            fee_token_account: ctx.accounts.fee_token_account.as_ref(),
+        };
+        let minted = minter.mint_net_of_fee(token_account.clone(), request.recipient, request.amount, request.fee_bps)?;
+        ctx.accounts.controller_store.reload()?;
+        let controller_store = &ctx.accounts.controller_store;
+
+        request.confirmed_amount = request.amount;
+        request.status = RequestStatus::Approved;
+        merchant_requests.settle_request(&request)?;
+
+        // persist right away so a repeated entry sees the updated state
+        request.exit(&crate::ID)?;
+        merchant_requests.exit(&crate::ID)?;
+
+        emit!(MintConfirmed {
+            nonce: request.nonce,
+            requester: request.requester,
+            recipient: request.recipient,
+            amount: request.amount,
+            btc_deposit_address: request.btc_deposit_address.clone(),
+            btc_txid: request.btc_txid.clone(),
+            timestamp: request.timestamp,
+            request_hash: request.hash,
+            fee: minted.fee,
+            mint_sequence: minted.mint_sequence,
+            fee_mint_sequence: minted.fee_mint_sequence,
+            total_minted: controller_store.total_minted,
+            total_burned: controller_store.total_burned,
+        });
+    }
+    Ok(())
+}
+
+// ---- Reject Mint Requests ----
+
+#[derive(Accounts)]
+pub struct RejectMintRequests<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
+    )]
+    pub members_store: Account<'info, MembersStore>,
+}
+
+pub fn reject_mint_requests_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RejectMintRequests<'info>>,
+    params: BatchMintParams,
+) -> Result<()> {
+    require!(!params.requests.is_empty(), FactoryError::EmptyBatch);
+    require!(
+        ctx.remaining_accounts.len() == params.requests.len() * SETTLE_ACCOUNTS_PER_REQUEST,
+        FactoryError::InvalidBatchAccounts
+    );
+
+    for (entry, accounts) in params
+        .requests
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(SETTLE_ACCOUNTS_PER_REQUEST))
+    {
+        let [request_info, merchant_requests_info] = accounts else {
+            return err!(FactoryError::InvalidBatchAccounts);
+        };
+        let mut request = load_pending_request(request_info, RequestType::Mint, entry.nonce, &entry.request_hash)?;
+        let mut merchant_requests = load_merchant_requests(merchant_requests_info, &request.requester)?;
+
+        request.status = RequestStatus::Rejected;
+        merchant_requests.settle_request(&request)?;
+
+        // persist right away so a repeated entry sees the updated state
+        request.exit(&crate::ID)?;
+        merchant_requests.exit(&crate::ID)?;
+
+        emit!(MintRejected {
+            nonce: request.nonce,
+            requester: request.requester,
+            amount: request.amount,
+            btc_deposit_address: request.btc_deposit_address.clone(),
+            btc_txid: request.btc_txid.clone(),
+            timestamp: request.timestamp,
+            request_hash: request.hash,
+        });
+    }
+    Ok(())
+}
+
+// ---- Confirm Burn Requests ----
+
+#[derive(Accounts)]
+pub struct ConfirmBurnRequests<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
+    )]
+    pub members_store: Account<'info, MembersStore>,
+}
+
+pub fn confirm_burn_requests_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConfirmBurnRequests<'info>>,
+    params: BatchBurnParams,
+) -> Result<()> {
+    require!(!params.requests.is_empty(), FactoryError::EmptyBatch);
+    require!(
+        ctx.remaining_accounts.len() == params.requests.len() * SETTLE_ACCOUNTS_PER_REQUEST,
+        FactoryError::InvalidBatchAccounts
+    );
+
+    // one output can't settle two burns
+    let mut vouts: Vec<u32> = params.requests.iter().map(|entry| entry.vout).collect();
+    vouts.sort_unstable();
+    require!(vouts.windows(2).all(|pair| pair[0] != pair[1]), FactoryError::DuplicateBtcVout);
+
+    for (entry, accounts) in params
+        .requests
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(SETTLE_ACCOUNTS_PER_REQUEST))
+    {
+        let [request_info, merchant_requests_info] = accounts else {
+            return err!(FactoryError::InvalidBatchAccounts);
+        };
+        let mut request = load_pending_request(request_info, RequestType::Burn, entry.nonce, &entry.request_hash)?;
+        let mut merchant_requests = load_merchant_requests(merchant_requests_info, &request.requester)?;
+
+        // each burn is paid by its own output of the shared transaction, burns
+        // with partial payouts need their payouts PDA and are confirmed on their own
+        require!(!request.has_partial_payouts(), FactoryError::PartialPayoutsRecorded);
+        request.record_payout(entry.btc_amount_sent, entry.btc_network_fee)?;
+        approve_burn_request(&mut request, &mut merchant_requests, &params.btc_txid, Some(entry.vout))?;
+
+        // persist right away so a repeated entry sees the updated state
+        request.exit(&crate::ID)?;
+        merchant_requests.exit(&crate::ID)?;
+    }
+    Ok(())
+}
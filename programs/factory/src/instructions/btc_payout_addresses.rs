@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+use members::MerchantInfo;
+use members::MERCHANT_INFO_SEED;
+use crate::errors::FactoryError;
+use crate::events::{BtcPayoutAddressAdded, BtcPayoutAddressRemoved};
+use crate::state::{BtcPayoutAllowlist, FactoryStore, MAX_BTC_ADDRESS_LEN, MAX_BTC_PAYOUT_ADDRESSES};
+use crate::BTC_PAYOUT_ALLOWLIST_SEED;
+use crate::FACTORY_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BtcPayoutAddressParams {
+    pub btc_address: String,
+}
+
+// ---- Add BTC Payout Address ----
+
+#[derive(Accounts)]
+pub struct AddBtcPayoutAddress<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MERCHANT_INFO_SEED, payer.key().as_ref()],
+        bump = merchant_info.bump,
+        seeds::program = controller_store.members
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BtcPayoutAllowlist::INIT_SPACE,
+        seeds = [BTC_PAYOUT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub btc_payout_allowlist: Account<'info, BtcPayoutAllowlist>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_btc_payout_address_handler(
+    ctx: Context<AddBtcPayoutAddress>,
+    params: BtcPayoutAddressParams,
+) -> Result<()> {
+    require!(
+        !params.btc_address.is_empty() && params.btc_address.len() <= MAX_BTC_ADDRESS_LEN,
+        FactoryError::InvalidBtcDepositAddress
+    );
+    require!(params.btc_address.is_ascii(), FactoryError::InvalidAddressCharacters);
+
+    let merchant_key = ctx.accounts.payer.key();
+    let allowlist = &mut ctx.accounts.btc_payout_allowlist;
+    require!(
+        !allowlist.is_allowed(&params.btc_address),
+        FactoryError::BtcPayoutAddressAlreadyAllowed
+    );
+    require!(
+        allowlist.btc_addresses.len() < MAX_BTC_PAYOUT_ADDRESSES,
+        FactoryError::TooManyBtcPayoutAddresses
+    );
+
+    allowlist.merchant = merchant_key;
+    allowlist.btc_addresses.push(params.btc_address.clone());
+    allowlist.bump = ctx.bumps.btc_payout_allowlist;
+
+    emit!(BtcPayoutAddressAdded {
+        merchant: merchant_key,
+        btc_address: params.btc_address,
+    });
+    Ok(())
+}
+
+// ---- Remove BTC Payout Address ----
+
+#[derive(Accounts)]
+pub struct RemoveBtcPayoutAddress<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BTC_PAYOUT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump = btc_payout_allowlist.bump
+    )]
+    pub btc_payout_allowlist: Account<'info, BtcPayoutAllowlist>,
+}
+
+// Pending burns keep their payout address, removal only applies to new burns
+pub fn remove_btc_payout_address_handler(
+    ctx: Context<RemoveBtcPayoutAddress>,
+    params: BtcPayoutAddressParams,
+) -> Result<()> {
+    let allowlist = &mut ctx.accounts.btc_payout_allowlist;
+    let index = allowlist
+        .btc_addresses
+        .iter()
+        .position(|address| *address == params.btc_address)
+        .ok_or(FactoryError::BtcPayoutAddressNotAllowed)?;
+    allowlist.btc_addresses.swap_remove(index);
+
+    emit!(BtcPayoutAddressRemoved {
+        merchant: ctx.accounts.payer.key(),
+        btc_address: params.btc_address,
+    });
+    Ok(())
+}
@@ -1,24 +1,34 @@
 use anchor_lang::prelude::*;
 use crate::errors::FactoryError;
-use crate::events::{Burned, BurnConfirmed};
-use crate::state::{FactoryStore, RequestAccount, RequestStatus, RequestType, MerchantBtcDepositAddress};
+use crate::events::{Burned, BurnConfirmed, BurnPartiallySettled};
+use crate::state::{BtcPayout, BtcPayoutAllowlist, BurnPayouts, FactoryStore, RequestAccount, RequestStatus, RequestType, MerchantBtcDepositAddress, MerchantRequests, merchant_tier_policy};
 use crate::FACTORY_SEED;
 use crate::BURN_REQUEST_SEED;
+use crate::BURN_PAYOUTS_SEED;
+use crate::BTC_PAYOUT_ALLOWLIST_SEED;
 use crate::MERCHANT_BTC_ADDRESS_SEED;
+use crate::MERCHANT_REQUESTS_SEED;
 use members::MembersStore;
 use members::MEMBERS_SEED;
 use members::MERCHANT_INFO_SEED;
 use members::MerchantInfo;
+use members::MerchantTier;
+use members::{DenylistEntry, DENYLIST_SEED};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, Mint, TokenAccount, TokenInterface, BurnChecked},
+    token_2022::spl_token_2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
+use controller::program::Controller as ControllerProgram;
+use controller::cpi as controller_cpi;
 use controller::CONTROLLER_SEED;
 use controller::ControllerStore;
+use controller::instructions as controller_instructions;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BurnParams {
     pub amount: u64,
+    pub btc_address: Option<String>, // defaults to the merchant BTC deposit address
 }
 
 #[derive(Accounts)]
@@ -44,6 +54,7 @@ pub struct Burn<'info> {
     )]
     pub request_account: Account<'info, RequestAccount>,
     #[account(
+        mut,
         seeds = [CONTROLLER_SEED],
         bump = controller_store.bump,
         seeds::program = factory_store.controller,
@@ -55,6 +66,17 @@ pub struct Burn<'info> {
         seeds::program = controller_store.members
     )]
     pub merchant_info: Account<'info, MerchantInfo>,
+    #[account(owner = controller_store.members)]
+    pub merchant_tier: Option<Account<'info, MerchantTier>>,
+    /// CHECK: members denylist PDA of the merchant, must not exist
+    #[account(
+        seeds = [DENYLIST_SEED, payer.key().as_ref()],
+        bump,
+        seeds::program = controller_store.members,
+        constraint = !DenylistEntry::is_denylisted(&requester_denylist_entry) @ FactoryError::AddressDenylisted
+    )]
+    pub requester_denylist_entry: UncheckedAccount<'info>,
+    // only required when paying out to the merchant BTC deposit address
     #[account(
         seeds = [
             MERCHANT_BTC_ADDRESS_SEED,
@@ -64,7 +86,21 @@ pub struct Burn<'info> {
         constraint = merchant_btc_address.merchant == payer.key() @ FactoryError::InvalidBtcAddress,
         constraint = !merchant_btc_address.btc_address.is_empty() @ FactoryError::InvalidBtcAddress
     )]
-    pub merchant_btc_address: Account<'info, MerchantBtcDepositAddress>,
+    pub merchant_btc_address: Option<Account<'info, MerchantBtcDepositAddress>>,
+    // only required when paying out to an address from the allowlist
+    #[account(
+        seeds = [BTC_PAYOUT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump = btc_payout_allowlist.bump
+    )]
+    pub btc_payout_allowlist: Option<Account<'info, BtcPayoutAllowlist>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MerchantRequests::INIT_SPACE,
+        seeds = [MERCHANT_REQUESTS_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
     #[account(
         mut,
         address = controller_store.token_mint,
@@ -78,19 +114,61 @@ pub struct Burn<'info> {
         associated_token::token_program = token_program
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
+    // Treasury token account, only required when a fee is charged
+    #[account(
+        mut,
+        address = factory_store.fee_recipient @ FactoryError::InvalidFeeRecipient,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = controller::ID)]
+    pub controller_program: Program<'info, ControllerProgram>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>, 
     pub system_program: Program<'info, System>,
 }
 
-pub fn burn_handler(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
+pub fn burn_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+    params: BurnParams,
+) -> Result<()> {
     require!(params.amount > 0, FactoryError::InvalidAmount);
 
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.merchant_info.is_compliant(timestamp),
+        FactoryError::MerchantComplianceExpired
+    );
+
     let factory_store = &mut ctx.accounts.factory_store;
     let merchant_key = ctx.accounts.payer.key();
 
-    // find the BTC address that the merchant wants to receive when burning
-    let btc_deposit_address: String = ctx.accounts.merchant_btc_address.btc_address.clone();
+    // find the BTC address that the merchant wants to receive when burning,
+    // a per-request address must be on its payout allowlist
+    let btc_deposit_address: String = match &params.btc_address {
+        Some(btc_address) => {
+            let allowlist = ctx
+                .accounts
+                .btc_payout_allowlist
+                .as_ref()
+                .ok_or(FactoryError::BtcPayoutAddressNotAllowed)?;
+            require!(allowlist.is_allowed(btc_address), FactoryError::BtcPayoutAddressNotAllowed);
+            btc_address.clone()
+        }
+        None => ctx
+            .accounts
+            .merchant_btc_address
+            .as_ref()
+            .ok_or(FactoryError::InvalidBtcAddress)?
+            .btc_address
+            .clone(),
+    };
+    // the merchant's tier policy, its fee rate replaces the global burn fee
+    let merchant_tier = merchant_tier_policy(&ctx.accounts.merchant_info, &ctx.accounts.merchant_tier)?;
+    let fee_bps = factory_store.fee_bps_for(&RequestType::Burn, merchant_tier);
+
+    // the fee must stay below the burned amount, checked before the request is opened
+    let fee = factory_store.calculate_fee(params.amount, fee_bps)?;
+
     let request = &mut ctx.accounts.request_account;
     
     // initialize the request data
@@ -99,8 +177,15 @@ pub fn burn_handler(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
     request.amount = params.amount;
     request.btc_deposit_address = btc_deposit_address.clone();
     request.btc_txid = "".to_string(); // initialize as empty, filled by custodian when confirmed
+    request.btc_deposit_timestamp = 0; // only set on mint requests
+    request.btc_amount_sent = 0;
+    request.btc_network_fee = 0;
+    request.confirmed_amount = 0;
+    request.btc_vout = None;
+    request.recipient = merchant_key;
+    request.fee_bps = fee_bps;
     request.nonce = factory_store.burn_request_counter;
-    request.timestamp = Clock::get()?.unix_timestamp;
+    request.timestamp = timestamp;
     request.status = RequestStatus::Pending;
     request.bump = ctx.bumps.request_account;
     request.hash = request.calculate_hash();
@@ -108,21 +193,72 @@ pub fn burn_handler(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
     // update the counter
     factory_store.burn_request_counter += 1;
 
-    // directly burn tokens from the user's account
-    let burn_cpi_accounts = BurnChecked {
-        mint: ctx.accounts.token_mint.to_account_info(),
-        from: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.payer.to_account_info(),
+    // track the open request against the merchant, applying its tier policy
+    let merchant_requests = &mut ctx.accounts.merchant_requests;
+    merchant_requests.merchant = merchant_key;
+    merchant_requests.bump = ctx.bumps.merchant_requests;
+    merchant_requests.open_request(&RequestType::Burn, params.amount, timestamp, merchant_tier)?;
+
+    // charge the fee on top of the burned amount from the merchant's balance
+    if fee > 0 {
+        let fee_token_account = ctx
+            .accounts
+            .fee_token_account
+            .as_ref()
+            .ok_or(FactoryError::MissingFeeRecipient)?;
+        let mut transfer_ix = spl_token_2022::instruction::transfer_checked(
+            ctx.accounts.token_program.key,
+            &ctx.accounts.token_account.key(),
+            &ctx.accounts.token_mint.key(),
+            &fee_token_account.key(),
+            ctx.accounts.payer.key,
+            &[],
+            fee,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        let mut account_infos = vec![
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            fee_token_account.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+        ];
+        // forward the transfer hook extra accounts when the mint has a hook
+        for account in ctx.remaining_accounts.iter() {
+            transfer_ix.accounts.push(if account.is_writable {
+                AccountMeta::new(account.key(), false)
+            } else {
+                AccountMeta::new_readonly(account.key(), false)
+            });
+            account_infos.push(account.clone());
+        }
+        anchor_lang::solana_program::program::invoke(&transfer_ix, &account_infos)?;
+    }
+
+    // burn tokens from the user's account through the controller
+    let cpi_program = ctx.accounts.controller_program.to_account_info();
+    let cpi_accounts = controller_cpi::accounts::_Burn {
+        factory_store: ctx.accounts.factory_store.to_account_info(),
+        controller_store: ctx.accounts.controller_store.to_account_info(),
+        owner: ctx.accounts.payer.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
     };
-    let burn_cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        burn_cpi_accounts,
-    );
-    token_interface::burn_checked(
-        burn_cpi_ctx,
-        params.amount,
-        ctx.accounts.token_mint.decimals,
-    )?;
+
+    // prepare PDA signature
+    let factory_seeds = &[FACTORY_SEED, &[ctx.accounts.factory_store.bump]];
+    let signer_seeds = &[&factory_seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+    controller_cpi::burn(cpi_ctx, controller_instructions::burn::BurnParams {
+        amount: params.amount,
+    })?;
+
+    // pick up the supply accounting updated by the controller
+    ctx.accounts.controller_store.reload()?;
+    let controller_store = &ctx.accounts.controller_store;
 
     // emit the event
     emit!(Burned {
@@ -132,6 +268,9 @@ pub fn burn_handler(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
         btc_deposit_address: btc_deposit_address.clone(),
         timestamp: request.timestamp,
         request_hash: request.hash,
+        fee,
+        total_minted: controller_store.total_minted,
+        total_burned: controller_store.total_burned,
     });
     Ok(())
 }
@@ -142,6 +281,8 @@ pub fn burn_handler(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
 pub struct ConfirmBurnParams {
     pub nonce: u64,
     pub btc_txid: String,
+    pub btc_amount_sent: u64,
+    pub btc_network_fee: u64,
     pub request_hash: [u8; 32],
 }   
 
@@ -168,6 +309,12 @@ pub struct ConfirmBurnRequest<'info> {
         constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash,
     )]
     pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
     #[account(
         seeds = [CONTROLLER_SEED],
         bump = controller_store.bump,
@@ -181,6 +328,13 @@ pub struct ConfirmBurnRequest<'info> {
         constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
     )]
     pub members_store: Account<'info, MembersStore>,
+    // required once partial payouts were recorded for the burn
+    #[account(
+        mut,
+        seeds = [BURN_PAYOUTS_SEED, &params.nonce.to_le_bytes()],
+        bump = burn_payouts.bump
+    )]
+    pub burn_payouts: Option<Account<'info, BurnPayouts>>,
 }
 
 pub fn confirm_burn_request_handler(
@@ -189,9 +343,131 @@ pub fn confirm_burn_request_handler(
 ) -> Result<()> {
     let request = &mut ctx.accounts.request_account;
 
+    // the final payout, on top of any partial ones already recorded
+    if request.has_partial_payouts() {
+        let burn_payouts = ctx
+            .accounts
+            .burn_payouts
+            .as_mut()
+            .ok_or(FactoryError::MissingBurnPayouts)?;
+        burn_payouts.record(BtcPayout {
+            btc_txid: params.btc_txid.clone(),
+            btc_amount_sent: params.btc_amount_sent,
+            btc_network_fee: params.btc_network_fee,
+            btc_vout: None,
+        })?;
+    }
+    request.record_payout(params.btc_amount_sent, params.btc_network_fee)?;
+    approve_burn_request(request, &mut ctx.accounts.merchant_requests, &params.btc_txid, None)
+}
+
+// ---- Record Burn Payout ----
+
+#[derive(Accounts)]
+#[instruction(params: ConfirmBurnParams)]
+pub struct RecordBurnPayout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        mut,
+        seeds = [
+            BURN_REQUEST_SEED, 
+            &params.nonce.to_le_bytes()
+        ],
+        bump = request_account.bump,
+        constraint = request_account.request_type == RequestType::Burn @ FactoryError::InvalidRequestType,
+        constraint = request_account.nonce == params.nonce @ FactoryError::InvalidNonce,
+        constraint = request_account.status == RequestStatus::Pending @ FactoryError::NotPendingRequest,
+        constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash,
+    )]
+    pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BurnPayouts::INIT_SPACE,
+        seeds = [BURN_PAYOUTS_SEED, &params.nonce.to_le_bytes()],
+        bump
+    )]
+    pub burn_payouts: Account<'info, BurnPayouts>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    pub system_program: Program<'info, System>,
+}
+
+// Record one of several BTC payouts settling a burn, the request is approved
+// once the payouts and their network fees cover the burned amount
+pub fn record_burn_payout_handler(
+    ctx: Context<RecordBurnPayout>,
+    params: ConfirmBurnParams,
+) -> Result<()> {
+    require!(params.btc_amount_sent > 0, FactoryError::InvalidAmount);
+
+    let burn_payouts = &mut ctx.accounts.burn_payouts;
+    burn_payouts.nonce = params.nonce;
+    burn_payouts.bump = ctx.bumps.burn_payouts;
+    burn_payouts.record(BtcPayout {
+        btc_txid: params.btc_txid.clone(),
+        btc_amount_sent: params.btc_amount_sent,
+        btc_network_fee: params.btc_network_fee,
+        btc_vout: None,
+    })?;
+
+    let request = &mut ctx.accounts.request_account;
+    request.record_payout(params.btc_amount_sent, params.btc_network_fee)?;
+
+    emit!(BurnPartiallySettled {
+        nonce: request.nonce,
+        requester: request.requester,
+        amount: request.amount,
+        btc_deposit_address: request.btc_deposit_address.clone(),
+        btc_txid: params.btc_txid.clone(),
+        btc_amount_sent: params.btc_amount_sent,
+        btc_network_fee: params.btc_network_fee,
+        total_btc_amount_sent: request.btc_amount_sent,
+        total_btc_network_fee: request.btc_network_fee,
+        timestamp: request.timestamp,
+        request_hash: request.hash,
+    });
+
+    if request.is_burn_covered() {
+        approve_burn_request(request, &mut ctx.accounts.merchant_requests, &params.btc_txid, None)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn approve_burn_request(
+    request: &mut RequestAccount,
+    merchant_requests: &mut MerchantRequests,
+    btc_txid: &str,
+    btc_vout: Option<u32>,
+) -> Result<()> {
     // update the txid and status
-    request.btc_txid = params.btc_txid.clone();
+    request.btc_txid = btc_txid.to_string();
+    request.btc_vout = btc_vout;
     request.status = RequestStatus::Approved;
+    merchant_requests.settle_request(request)?;
 
     // emit the event
     emit!(BurnConfirmed {
@@ -199,9 +475,12 @@ pub fn confirm_burn_request_handler(
         requester: request.requester,
         amount: request.amount,
         btc_deposit_address: request.btc_deposit_address.clone(),
-        btc_txid: params.btc_txid.clone(),
+        btc_txid: request.btc_txid.clone(),
+        btc_vout,
+        btc_amount_sent: request.btc_amount_sent,
+        btc_network_fee: request.btc_network_fee,
         timestamp: request.timestamp,
         request_hash: request.hash,
     });
     Ok(())
-} 
+}
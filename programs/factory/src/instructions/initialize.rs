@@ -29,5 +29,10 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     factory_store.burn_request_counter = 0;
     factory_store.controller = params.controller;
     factory_store.bump = ctx.bumps.factory_store;
+    factory_store.mint_fee_bps = 0;
+    factory_store.burn_fee_bps = 0;
+    factory_store.min_fee = 0;
+    factory_store.fee_recipient = Pubkey::default();
+    factory_store.pending_fee_config = None;
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::FactoryError;
+use crate::events::FactoryStoreMigrated;
+use crate::state::{FactoryStore, FactoryStoreV0};
+use crate::FACTORY_SEED;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+
+// Rewrites a factory store created with the original layout into the current
+// one, the account is grown in place and the controller owner tops up its rent
+#[derive(Accounts)]
+pub struct MigrateFactoryStore<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: still in the legacy layout, owner and discriminator are checked by the handler
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump,
+        owner = crate::ID @ FactoryError::InvalidFactoryAddress
+    )]
+    pub factory_store: UncheckedAccount<'info>,
+    // checked against the controller recorded in the legacy store by the handler
+    #[account(has_one = owner @ FactoryError::Unauthorized)]
+    pub controller_store: Account<'info, ControllerStore>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateFactoryStore>) -> Result<()> {
+    let factory_store_info = ctx.accounts.factory_store.to_account_info();
+    require!(
+        factory_store_info.data_len() == 8 + FactoryStoreV0::INIT_SPACE,
+        FactoryError::AlreadyMigrated
+    );
+    let legacy = {
+        let data = factory_store_info.try_borrow_data()?;
+        require!(
+            data[..8] == *FactoryStore::DISCRIMINATOR,
+            FactoryError::AlreadyMigrated
+        );
+        FactoryStoreV0::deserialize(&mut &data[8..])?
+    };
+    let (expected_controller_store, _) =
+        Pubkey::find_program_address(&[CONTROLLER_SEED], &legacy.controller);
+    require_keys_eq!(
+        ctx.accounts.controller_store.key(),
+        expected_controller_store,
+        FactoryError::InvalidControllerStore
+    );
+
+    // fees stay disabled until the controller owner calls set_fee_config
+    let factory_store = FactoryStore {
+        mint_request_counter: legacy.mint_request_counter,
+        burn_request_counter: legacy.burn_request_counter,
+        controller: legacy.controller,
+        bump: legacy.bump,
+        mint_fee_bps: 0,
+        burn_fee_bps: 0,
+        min_fee: 0,
+        fee_recipient: Pubkey::default(),
+        pending_fee_config: None,
+    };
+
+    let new_len = 8 + FactoryStore::INIT_SPACE;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(factory_store_info.lamports());
+    if rent_due > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: factory_store_info.clone(),
+            },
+        );
+        transfer(cpi_ctx, rent_due)?;
+    }
+    factory_store_info.realloc(new_len, false)?;
+    factory_store.try_serialize(&mut &mut factory_store_info.try_borrow_mut_data()?[..])?;
+
+    emit!(FactoryStoreMigrated {
+        controller: factory_store.controller,
+        mint_request_counter: factory_store.mint_request_counter,
+        burn_request_counter: factory_store.burn_request_counter,
+    });
+    Ok(())
+}
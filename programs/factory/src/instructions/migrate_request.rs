@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::FactoryError;
+use crate::events::RequestMigrated;
+use crate::state::{FactoryStore, MerchantRequests, RequestAccount, RequestAccountV0, RequestStatus, RequestType};
+use crate::FACTORY_SEED;
+use crate::MERCHANT_REQUESTS_SEED;
+use controller::CONTROLLER_SEED;
+use members::MEMBERS_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateRequestParams {
+    pub request_type: RequestType,
+    pub nonce: u64,
+    pub requester: Pubkey,
+}
+
+// Rewrites a request opened with the original layout into the current one, the
+// account is grown in place and the custodian tops up its rent
+#[derive(Accounts)]
+#[instruction(params: MigrateRequestParams)]
+pub struct MigrateRequest<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, controller::ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian,
+    )]
+    pub members_store: Account<'info, members::MembersStore>,
+    /// CHECK: still in the legacy layout, owner and discriminator are checked by the handler
+    #[account(
+        mut,
+        seeds = [params.request_type.seed(), &params.nonce.to_le_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub request_account: UncheckedAccount<'info>,
+    // a pending request is counted against its merchant, which may predate the counters
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MerchantRequests::INIT_SPACE,
+        seeds = [MERCHANT_REQUESTS_SEED, params.requester.as_ref()],
+        bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateRequest>, params: MigrateRequestParams) -> Result<()> {
+    let request_info = ctx.accounts.request_account.to_account_info();
+    // legacy requests were allocated for the longest addresses and txids
+    require!(
+        request_info.data_len() == 8 + RequestAccountV0::INIT_SPACE,
+        FactoryError::RequestAlreadyMigrated
+    );
+    let legacy = {
+        let data = request_info.try_borrow_data()?;
+        require!(
+            data[..8] == *RequestAccount::DISCRIMINATOR,
+            FactoryError::RequestAlreadyMigrated
+        );
+        RequestAccountV0::deserialize(&mut &data[8..])?
+    };
+    require!(legacy.request_type == params.request_type, FactoryError::InvalidRequestType);
+    require!(legacy.nonce == params.nonce, FactoryError::InvalidNonce);
+    require_keys_eq!(legacy.requester, params.requester, FactoryError::InvalidMerchant);
+
+    // A pending mint is charged at confirmation, so it takes the current rate.
+    // Legacy merchants have no tier yet, the factory-wide rate applies. Burns
+    // were charged when opened, before fees existed.
+    let fee_bps = match legacy.request_type {
+        RequestType::Mint => ctx.accounts.factory_store.fee_bps_for(&RequestType::Mint, None),
+        RequestType::Burn => 0,
+    };
+
+    // an approved legacy mint minted the full amount
+    let confirmed_amount = match (&legacy.request_type, &legacy.status) {
+        (RequestType::Mint, RequestStatus::Approved) => legacy.amount,
+        _ => 0,
+    };
+
+    // when the BTC was sent was not recorded, it is left at 0 like on burns
+    let mut request = RequestAccount {
+        request_type: legacy.request_type,
+        requester: legacy.requester,
+        amount: legacy.amount,
+        btc_deposit_address: legacy.btc_deposit_address,
+        btc_txid: legacy.btc_txid,
+        btc_deposit_timestamp: 0,
+        nonce: legacy.nonce,
+        timestamp: legacy.timestamp,
+        status: legacy.status,
+        hash: [0; 32],
+        bump: legacy.bump,
+        btc_amount_sent: 0,
+        btc_network_fee: 0,
+        confirmed_amount,
+        btc_vout: None,
+        recipient: legacy.requester,
+        fee_bps,
+    };
+    request.hash = request.calculate_hash();
+
+    // Backfill the merchant's counters so it can't resign with the request
+    // pending. The daily cap only covers requests opened since the upgrade.
+    let merchant_requests = &mut ctx.accounts.merchant_requests;
+    merchant_requests.merchant = legacy.requester;
+    merchant_requests.bump = ctx.bumps.merchant_requests;
+    if request.status == RequestStatus::Pending {
+        merchant_requests.track_request(&request.request_type)?;
+    }
+
+    let new_len = 8 + RequestAccount::INIT_SPACE;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(request_info.lamports());
+    if rent_due > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: request_info.clone(),
+            },
+        );
+        transfer(cpi_ctx, rent_due)?;
+    }
+    request_info.realloc(new_len, false)?;
+    request.try_serialize(&mut &mut request_info.try_borrow_mut_data()?[..])?;
+
+    emit!(RequestMigrated {
+        nonce: request.nonce,
+        request_type: request.request_type,
+        requester: request.requester,
+        fee_bps,
+        previous_request_hash: legacy.hash,
+        request_hash: request.hash,
+    });
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+use members::MerchantInfo;
+use members::MERCHANT_INFO_SEED;
+use crate::errors::FactoryError;
+use crate::events::{MintRecipientAdded, MintRecipientRemoved};
+use crate::state::{FactoryStore, MintRecipientAllowlist, MAX_MINT_RECIPIENTS};
+use crate::FACTORY_SEED;
+use crate::MINT_RECIPIENT_ALLOWLIST_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintRecipientParams {
+    pub recipient: Pubkey,
+}
+
+// ---- Add Mint Recipient ----
+
+#[derive(Accounts)]
+pub struct AddMintRecipient<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MERCHANT_INFO_SEED, payer.key().as_ref()],
+        bump = merchant_info.bump,
+        seeds::program = controller_store.members
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintRecipientAllowlist::INIT_SPACE,
+        seeds = [MINT_RECIPIENT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub mint_recipient_allowlist: Account<'info, MintRecipientAllowlist>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_mint_recipient_handler(ctx: Context<AddMintRecipient>, params: MintRecipientParams) -> Result<()> {
+    let merchant_key = ctx.accounts.payer.key();
+    require!(
+        params.recipient != Pubkey::default() && params.recipient != merchant_key,
+        FactoryError::InvalidToAddress
+    );
+
+    let allowlist = &mut ctx.accounts.mint_recipient_allowlist;
+    require!(!allowlist.is_allowed(&params.recipient), FactoryError::RecipientAlreadyAllowed);
+    require!(allowlist.recipients.len() < MAX_MINT_RECIPIENTS, FactoryError::TooManyRecipients);
+
+    allowlist.merchant = merchant_key;
+    allowlist.recipients.push(params.recipient);
+    allowlist.bump = ctx.bumps.mint_recipient_allowlist;
+
+    emit!(MintRecipientAdded {
+        merchant: merchant_key,
+        recipient: params.recipient,
+    });
+    Ok(())
+}
+
+// ---- Remove Mint Recipient ----
+
+#[derive(Accounts)]
+pub struct RemoveMintRecipient<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_RECIPIENT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump = mint_recipient_allowlist.bump
+    )]
+    pub mint_recipient_allowlist: Account<'info, MintRecipientAllowlist>,
+}
+
+// Pending requests keep their recipient, removal only applies to new requests
+pub fn remove_mint_recipient_handler(ctx: Context<RemoveMintRecipient>, params: MintRecipientParams) -> Result<()> {
+    let allowlist = &mut ctx.accounts.mint_recipient_allowlist;
+    let index = allowlist
+        .recipients
+        .iter()
+        .position(|recipient| *recipient == params.recipient)
+        .ok_or(FactoryError::RecipientNotAllowed)?;
+    allowlist.recipients.swap_remove(index);
+
+    emit!(MintRecipientRemoved {
+        merchant: ctx.accounts.payer.key(),
+        recipient: params.recipient,
+    });
+    Ok(())
+}
@@ -2,14 +2,18 @@ use anchor_lang::prelude::*;
 use controller::program::Controller as ControllerProgram;
 use controller::cpi as controller_cpi;
 use crate::errors::FactoryError;
-use crate::events::{MintRequestAdd, MintRequestCancel, MintConfirmed, MintRejected};
-use crate::state::{FactoryStore, RequestAccount, RequestStatus, RequestType, CustodianBtcDepositAddress};
+use crate::events::{MintRequestAdd, MintRequestCancel, MintConfirmed, MintPartiallyConfirmed, MintRejected};
+use crate::state::{FactoryStore, RequestAccount, RequestStatus, RequestType, CustodianBtcDepositAddress, MerchantRequests, MintRecipientAllowlist, merchant_tier_policy, MAX_ADJUSTMENT_REASON_LEN};
 use crate::CUSTODIAN_BTC_ADDRESS_SEED;
 use crate::FACTORY_SEED;
+use crate::MERCHANT_REQUESTS_SEED;
 use crate::MINT_REQUEST_SEED;
+use crate::MINT_RECIPIENT_ALLOWLIST_SEED;
 use members::MEMBERS_SEED;
 use members::MERCHANT_INFO_SEED;
 use members::MerchantInfo;
+use members::MerchantTier;
+use members::{DenylistEntry, DENYLIST_SEED};
 use controller::CONTROLLER_SEED;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -24,6 +28,8 @@ pub struct MintParams {
     pub amount: u64,
     pub btc_txid: String,
     pub btc_deposit_address: String,
+    pub recipient: Option<Pubkey>, // defaults to the requester
+    pub btc_deposit_timestamp: Option<i64>, // when the BTC was sent, defaults to the request time
 }
 
 #[derive(Accounts)]
@@ -61,16 +67,41 @@ pub struct AddMintRequest<'info> {
         seeds::program = controller_store.members
     )]
     pub merchant_info: Account<'info, MerchantInfo>,
+    #[account(owner = controller_store.members)]
+    pub merchant_tier: Option<Account<'info, MerchantTier>>,
+    /// CHECK: members denylist PDA of the merchant, must not exist
+    #[account(
+        seeds = [DENYLIST_SEED, payer.key().as_ref()],
+        bump,
+        seeds::program = controller_store.members,
+        constraint = !DenylistEntry::is_denylisted(&requester_denylist_entry) @ FactoryError::AddressDenylisted
+    )]
+    pub requester_denylist_entry: UncheckedAccount<'info>,
     #[account(
         seeds = [
             CUSTODIAN_BTC_ADDRESS_SEED,
-            payer.key().as_ref()
+            payer.key().as_ref(),
+            &custodian_btc_address.index.to_le_bytes()
         ],
         bump = custodian_btc_address.bump,
         constraint = custodian_btc_address.merchant == payer.key() @ FactoryError::InvalidMerchant,
         constraint = custodian_btc_address.btc_address == params.btc_deposit_address @ FactoryError::WrongBtcDepositAddress
     )]
     pub custodian_btc_address: Account<'info, CustodianBtcDepositAddress>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MerchantRequests::INIT_SPACE,
+        seeds = [MERCHANT_REQUESTS_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
+    // only required when minting to a designated recipient
+    #[account(
+        seeds = [MINT_RECIPIENT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump = mint_recipient_allowlist.bump
+    )]
+    pub mint_recipient_allowlist: Option<Account<'info, MintRecipientAllowlist>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -83,6 +114,42 @@ pub fn add_mint_request_handler(
 
     require!(!params.btc_deposit_address.is_empty(), FactoryError::InvalidBtcAddress);
 
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.merchant_info.is_compliant(timestamp),
+        FactoryError::MerchantComplianceExpired
+    );
+
+    // Any of the merchant's addresses is accepted if it was active when the BTC was sent
+    let btc_deposit_timestamp = params.btc_deposit_timestamp.unwrap_or(timestamp);
+    require!(btc_deposit_timestamp <= timestamp, FactoryError::InvalidDepositTimestamp);
+    require!(
+        ctx.accounts.custodian_btc_address.was_active_at(btc_deposit_timestamp)
+            && ctx.accounts.custodian_btc_address.accepts_claims_at(timestamp),
+        FactoryError::BtcDepositAddressNotActive
+    );
+
+    // A recipient other than the merchant must be on its allowlist
+    let recipient = match params.recipient {
+        Some(recipient) if recipient != merchant_key => {
+            let allowlist = ctx
+                .accounts
+                .mint_recipient_allowlist
+                .as_ref()
+                .ok_or(FactoryError::RecipientNotAllowed)?;
+            require!(allowlist.is_allowed(&recipient), FactoryError::RecipientNotAllowed);
+            recipient
+        }
+        _ => merchant_key,
+    };
+
+    // The merchant's tier policy, its fee rate applies when the request is confirmed
+    let merchant_tier = merchant_tier_policy(&ctx.accounts.merchant_info, &ctx.accounts.merchant_tier)?;
+    let fee_bps = factory_store.fee_bps_for(&RequestType::Mint, merchant_tier);
+
+    // The fee must leave something to mint, rather than failing only at confirmation
+    factory_store.calculate_fee(params.amount, fee_bps)?;
+
     let request = &mut ctx.accounts.request_account;
     
     // Set request account content
@@ -91,8 +158,12 @@ pub fn add_mint_request_handler(
     request.amount = params.amount;
     request.btc_deposit_address = params.btc_deposit_address.clone();
     request.btc_txid = params.btc_txid.clone();
+    request.btc_deposit_timestamp = btc_deposit_timestamp;
+    request.confirmed_amount = 0;
+    request.recipient = recipient;
+    request.fee_bps = fee_bps;
     request.nonce = factory_store.mint_request_counter;
-    request.timestamp = Clock::get()?.unix_timestamp;
+    request.timestamp = timestamp;
     request.status = RequestStatus::Pending;
     request.bump = ctx.bumps.request_account;
     request.hash = request.calculate_hash();
@@ -100,12 +171,20 @@ pub fn add_mint_request_handler(
     // Update factory counter
     factory_store.mint_request_counter += 1;
 
+    // Track the open request against the merchant, applying its tier policy
+    let merchant_requests = &mut ctx.accounts.merchant_requests;
+    merchant_requests.merchant = merchant_key;
+    merchant_requests.bump = ctx.bumps.merchant_requests;
+    merchant_requests.open_request(&RequestType::Mint, params.amount, timestamp, merchant_tier)?;
+
     // Emit event
     emit!(MintRequestAdd {
         nonce: request.nonce,
         requester: merchant_key,
+        recipient,
         amount: params.amount,
         btc_deposit_address: params.btc_deposit_address.clone(),
+        btc_deposit_timestamp,
         btc_txid: params.btc_txid.clone(),
         timestamp: request.timestamp,
         request_hash: request.hash,
@@ -145,12 +224,19 @@ pub struct CancelMintRequest<'info> {
         constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash,
     )]
     pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
 }
 
 pub fn cancel_mint_request_handler(ctx: Context<CancelMintRequest>, params: CancelMintParams) -> Result<()> {
     let request = &mut ctx.accounts.request_account;
     // Update status
     request.status = RequestStatus::Canceled;
+    ctx.accounts.merchant_requests.settle_request(request)?;
     // Emit event
     emit!(MintRequestCancel {
         nonce: request.nonce,
@@ -178,6 +264,7 @@ pub struct ConfirmMintRequest<'info> {
     )]
     pub factory_store: Account<'info, FactoryStore>,
     #[account(
+        mut,
         seeds = [CONTROLLER_SEED],
         bump = controller_store.bump,
         seeds::program = factory_store.controller,
@@ -203,17 +290,31 @@ pub struct ConfirmMintRequest<'info> {
         constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash,
     )]
     pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
     #[account(
         mut,
         address = controller_store.token_mint,
         mint::token_program = token_program,
     )]
     pub token_mint: InterfaceAccount<'info, Mint>,
-    /// CHECK: Verify that to_address matches request_account.requester
+    /// CHECK: Verify that to_address matches request_account.recipient
     #[account(
-        constraint = to_address.key() == request_account.requester @ FactoryError::InvalidToAddress
+        constraint = to_address.key() == request_account.recipient @ FactoryError::InvalidToAddress
     )]
     pub to_address: AccountInfo<'info>,
+    /// CHECK: members denylist PDA of the mint recipient, must not exist
+    #[account(
+        seeds = [DENYLIST_SEED, to_address.key().as_ref()],
+        bump,
+        seeds::program = controller_store.members,
+        constraint = !DenylistEntry::is_denylisted(&to_address_denylist_entry) @ FactoryError::AddressDenylisted
+    )]
+    pub to_address_denylist_entry: UncheckedAccount<'info>,
     #[account(
         init_if_needed,
         payer = payer,
@@ -222,6 +323,12 @@ pub struct ConfirmMintRequest<'info> {
         associated_token::token_program = token_program
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
+    // Treasury token account, only required when a fee is charged
+    #[account(
+        mut,
+        address = factory_store.fee_recipient @ FactoryError::InvalidFeeRecipient,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(address = controller::ID)]
     pub controller_program: Program<'info, ControllerProgram>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -229,44 +336,274 @@ pub struct ConfirmMintRequest<'info> {
     pub system_program: Program<'info, System>,
 }
 
+// Accounts needed to mint through the controller, shared by the single and
+// batch confirmations
+pub(crate) struct ControllerMint<'a, 'info> {
+    pub controller_program: AccountInfo<'info>,
+    pub factory_store: &'a Account<'info, FactoryStore>,
+    pub controller_store: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub fee_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+// Result of a confirmed mint, every controller mint has its own sequence number
+pub(crate) struct MintedNetOfFee {
+    pub fee: u64,
+    pub mint_sequence: u64,     // sequence of the mint to the recipient
+    pub fee_mint_sequence: u64, // sequence of the mint to the treasury, 0 when no fee was charged
+}
+
+impl<'info> ControllerMint<'_, 'info> {
+    // Mint `amount` to the associated token account of `to` through the controller
+    // and return the sequence number it was assigned
+    fn mint(&self, token_account: AccountInfo<'info>, to: Pubkey, amount: u64) -> Result<u64> {
+        let cpi_accounts = controller_cpi::accounts::_Mint {
+            factory_store: self.factory_store.to_account_info(),
+            controller_store: self.controller_store.clone(),
+            token_mint: self.token_mint.clone(),
+            token_account,
+            token_program: self.token_program.clone(),
+            associated_token_program: self.associated_token_program.clone(),
+        };
+
+        // prepare PDA signature
+        let factory_seeds = &[FACTORY_SEED, &[self.factory_store.bump]];
+        let signer_seeds = &[&factory_seeds[..]];
+
+        // use with_signer to pass PDA signature
+        let cpi_ctx = CpiContext::new_with_signer(self.controller_program.clone(), cpi_accounts, signer_seeds);
+
+        controller_cpi::mint(cpi_ctx, controller_instructions::mint::MintParams { to, amount })?;
+
+        let controller_store = ControllerStore::try_deserialize(&mut &self.controller_store.try_borrow_data()?[..])?;
+        Ok(controller_store.mint_sequence)
+    }
+
+    // Mint `amount` for a confirmed request, net of the protocol fee at `fee_bps`
+    // which goes to the treasury in a second controller mint
+    pub fn mint_net_of_fee(&self, token_account: AccountInfo<'info>, to: Pubkey, amount: u64, fee_bps: u16) -> Result<MintedNetOfFee> {
+        let fee = self.factory_store.calculate_fee(amount, fee_bps)?;
+
+        // mint the amount net of the fee to the merchant
+        let mint_sequence = self.mint(token_account, to, amount - fee)?;
+
+        // and the fee to the treasury
+        let mut fee_mint_sequence = 0;
+        if fee > 0 {
+            let fee_token_account = self.fee_token_account.ok_or(FactoryError::MissingFeeRecipient)?;
+            fee_mint_sequence = self.mint(fee_token_account.to_account_info(), fee_token_account.owner, fee)?;
+        }
+        Ok(MintedNetOfFee { fee, mint_sequence, fee_mint_sequence })
+    }
+}
+
+// Mint through the controller with the confirmation accounts
+impl<'info> ConfirmMintRequest<'info> {
+    fn controller_mint(&self) -> ControllerMint<'_, 'info> {
+        ControllerMint {
+            controller_program: self.controller_program.to_account_info(),
+            factory_store: &self.factory_store,
+            controller_store: self.controller_store.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            fee_token_account: self.fee_token_account.as_ref(),
+        }
+    }
+}
+
 pub fn confirm_mint_request_handler(ctx: Context<ConfirmMintRequest>, params: ConfirmMintParams) -> Result<()> {
+    // Mint the requested amount and pick up the supply accounting updated by the controller
+    let amount = ctx.accounts.request_account.amount;
+    let minted = ctx.accounts.controller_mint().mint_net_of_fee(
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.to_address.key(),
+        amount,
+        ctx.accounts.request_account.fee_bps,
+    )?;
+    ctx.accounts.controller_store.reload()?;
+    let controller_store = &ctx.accounts.controller_store;
     let request = &mut ctx.accounts.request_account;
-    
-    // call the mint method of controller
-    let cpi_program = ctx.accounts.controller_program.to_account_info();
-    let cpi_accounts = controller_cpi::accounts::_Mint {
-        factory_store: ctx.accounts.factory_store.to_account_info(),
-        controller_store: ctx.accounts.controller_store.to_account_info(),
-        token_mint: ctx.accounts.token_mint.to_account_info(),
-        token_account: ctx.accounts.token_account.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-    };
-
-    // prepare PDA signature
-    let factory_seeds = &[FACTORY_SEED, &[ctx.accounts.factory_store.bump]];
-    let signer_seeds = &[&factory_seeds[..]];
-    
-    // use with_signer to pass PDA signature
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    
-    controller_cpi::mint(cpi_ctx, controller_instructions::mint::MintParams {
-        to: ctx.accounts.to_address.key(),
-        amount: request.amount,
-    })?;
 
     // Modify the request status
+    request.confirmed_amount = amount;
     request.status = RequestStatus::Approved;
+    ctx.accounts.merchant_requests.settle_request(request)?;
 
     // Emit event
     emit!(MintConfirmed {
         nonce: request.nonce,
         requester: request.requester,
+        recipient: request.recipient,
         amount: request.amount,
         btc_deposit_address: request.btc_deposit_address.clone(),
         btc_txid: request.btc_txid.clone(),
         timestamp: request.timestamp,
         request_hash: request.hash,
+        fee: minted.fee,
+        mint_sequence: minted.mint_sequence,
+        fee_mint_sequence: minted.fee_mint_sequence,
+        total_minted: controller_store.total_minted,
+        total_burned: controller_store.total_burned,
+    });
+    Ok(())
+}
+
+// ---- Confirm Mint Request Partially ----
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfirmMintPartialParams {
+    pub nonce: u64,
+    pub request_hash: [u8; 32],
+    pub amount: u64,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ConfirmMintPartialParams)]
+pub struct ConfirmMintRequestPartial<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian
+    )]
+    pub members_store: Account<'info, members::MembersStore>,
+    #[account(
+        mut,
+        seeds = [
+            MINT_REQUEST_SEED, 
+            &params.nonce.to_le_bytes()
+        ],
+        bump = request_account.bump,
+        constraint = request_account.request_type == RequestType::Mint @ FactoryError::InvalidRequestType,
+        constraint = request_account.nonce == params.nonce @ FactoryError::InvalidNonce,
+        constraint = request_account.status == RequestStatus::Pending @ FactoryError::NotPendingRequest,
+        constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash,
+    )]
+    pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
+    #[account(
+        mut,
+        address = controller_store.token_mint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Verify that to_address matches request_account.recipient
+    #[account(
+        constraint = to_address.key() == request_account.recipient @ FactoryError::InvalidToAddress
+    )]
+    pub to_address: AccountInfo<'info>,
+    /// CHECK: members denylist PDA of the mint recipient, must not exist
+    #[account(
+        seeds = [DENYLIST_SEED, to_address.key().as_ref()],
+        bump,
+        seeds::program = controller_store.members,
+        constraint = !DenylistEntry::is_denylisted(&to_address_denylist_entry) @ FactoryError::AddressDenylisted
+    )]
+    pub to_address_denylist_entry: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = to_address,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    // Treasury token account, only required when a fee is charged
+    #[account(
+        mut,
+        address = factory_store.fee_recipient @ FactoryError::InvalidFeeRecipient,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = controller::ID)]
+    pub controller_program: Program<'info, ControllerProgram>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>, 
+    pub system_program: Program<'info, System>,
+}
+
+// Mint through the controller with the confirmation accounts
+impl<'info> ConfirmMintRequestPartial<'info> {
+    fn controller_mint(&self) -> ControllerMint<'_, 'info> {
+        ControllerMint {
+            controller_program: self.controller_program.to_account_info(),
+            factory_store: &self.factory_store,
+            controller_store: self.controller_store.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            fee_token_account: self.fee_token_account.as_ref(),
+        }
+    }
+}
+
+pub fn confirm_mint_request_partial_handler(
+    ctx: Context<ConfirmMintRequestPartial>,
+    params: ConfirmMintPartialParams,
+) -> Result<()> {
+    require!(
+        params.amount > 0 && params.amount < ctx.accounts.request_account.amount,
+        FactoryError::InvalidAmount
+    );
+    require!(
+        !params.reason.is_empty() && params.reason.len() <= MAX_ADJUSTMENT_REASON_LEN,
+        FactoryError::InvalidAdjustmentReason
+    );
+
+    // Mint the deposited amount and pick up the supply accounting updated by the controller
+    let minted = ctx.accounts.controller_mint().mint_net_of_fee(
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.to_address.key(),
+        params.amount,
+        ctx.accounts.request_account.fee_bps,
+    )?;
+    ctx.accounts.controller_store.reload()?;
+    let controller_store = &ctx.accounts.controller_store;
+    let request = &mut ctx.accounts.request_account;
+
+    // Record the adjusted amount next to the requested one
+    request.confirmed_amount = params.amount;
+    request.status = RequestStatus::PartiallyApproved;
+    ctx.accounts.merchant_requests.settle_request(request)?;
+
+    emit!(MintPartiallyConfirmed {
+        nonce: request.nonce,
+        requester: request.requester,
+        recipient: request.recipient,
+        requested_amount: request.amount,
+        confirmed_amount: params.amount,
+        reason: params.reason,
+        btc_deposit_address: request.btc_deposit_address.clone(),
+        btc_txid: request.btc_txid.clone(),
+        timestamp: request.timestamp,
+        request_hash: request.hash,
+        fee: minted.fee,
+        mint_sequence: minted.mint_sequence,
+        fee_mint_sequence: minted.fee_mint_sequence,
+        total_minted: controller_store.total_minted,
+        total_burned: controller_store.total_burned,
     });
     Ok(())
 }
@@ -302,6 +639,12 @@ pub struct RejectMintRequest<'info> {
         constraint = request_account.hash == params.request_hash @ FactoryError::MismatchRequestHash
     )]
     pub request_account: Account<'info, RequestAccount>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_REQUESTS_SEED, request_account.requester.as_ref()],
+        bump = merchant_requests.bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
     #[account(
         seeds = [CONTROLLER_SEED],
         bump = controller_store.bump,
@@ -322,6 +665,7 @@ pub fn reject_mint_request_handler(ctx: Context<RejectMintRequest>, params: Reje
     
     // Update the request status
     request.status = RequestStatus::Rejected;
+    ctx.accounts.merchant_requests.settle_request(request)?;
 
     // Send the event
     emit!(MintRejected {
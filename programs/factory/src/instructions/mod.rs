@@ -1,11 +1,25 @@
 pub mod initialize;
+pub mod migrate_factory_store;
+pub mod migrate_request;
 pub mod set_custodian_btc_deposit_address;
 pub mod set_merchant_btc_deposit_address;
 pub mod mint_requests;
 pub mod burn;
+pub mod resign_merchant;
+pub mod batch_requests;
+pub mod mint_recipients;
+pub mod btc_payout_addresses;
+pub mod set_fee_config;
 
 pub use initialize::*;
+pub use migrate_factory_store::*;
+pub use migrate_request::*;
 pub use set_custodian_btc_deposit_address::*;
 pub use set_merchant_btc_deposit_address::*;
 pub use mint_requests::*;
 pub use burn::*;
+pub use resign_merchant::*;
+pub use batch_requests::*;
+pub use mint_recipients::*;
+pub use btc_payout_addresses::*;
+pub use set_fee_config::*;
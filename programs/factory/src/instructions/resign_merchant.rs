@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+use members::cpi as members_cpi;
+use members::program::Members as MembersProgram;
+use members::MembersStore;
+use members::MerchantInfo;
+use members::MEMBERS_SEED;
+use members::MERCHANT_INFO_SEED;
+use crate::errors::FactoryError;
+use crate::state::{CustodianBtcAddressBook, CustodianBtcDepositAddress, FactoryStore, MerchantRequests};
+use crate::BTC_PAYOUT_ALLOWLIST_SEED;
+use crate::CUSTODIAN_BTC_ADDRESS_BOOK_SEED;
+use crate::FACTORY_SEED;
+use crate::MERCHANT_BTC_ADDRESS_SEED;
+use crate::MERCHANT_REQUESTS_SEED;
+use crate::MINT_RECIPIENT_ALLOWLIST_SEED;
+
+#[derive(Accounts)]
+pub struct ResignMerchant<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    #[account(
+        mut,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    /// CHECK: rent receiver for the merchant info account, verified against members_store.owner
+    #[account(
+        mut,
+        address = members_store.owner @ FactoryError::Unauthorized
+    )]
+    pub members_owner: UncheckedAccount<'info>,
+    /// CHECK: rent receiver for the custodian deposit address accounts, verified against members_store.custodian
+    #[account(
+        mut,
+        address = members_store.custodian @ FactoryError::NotCustodian
+    )]
+    pub custodian: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_INFO_SEED, payer.key().as_ref()],
+        bump = merchant_info.bump,
+        seeds::program = controller_store.members
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+    // created on the fly for merchants that never opened a request, so the
+    // pending check below can't be skipped by omitting the account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MerchantRequests::INIT_SPACE,
+        seeds = [MERCHANT_REQUESTS_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub merchant_requests: Account<'info, MerchantRequests>,
+    // deposit addresses and the allowlists only exist once they have been set. They are
+    // pinned by seeds rather than optional, so leaving one out can't keep it open;
+    // the handler closes whichever exist. The indexed deposit addresses listed in the
+    // book are passed as remaining accounts
+    /// CHECK: custodian BTC address book PDA, may not exist
+    #[account(
+        mut,
+        seeds = [CUSTODIAN_BTC_ADDRESS_BOOK_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub custodian_btc_address_book: UncheckedAccount<'info>,
+    /// CHECK: merchant BTC address PDA, may not exist
+    #[account(
+        mut,
+        seeds = [MERCHANT_BTC_ADDRESS_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub merchant_btc_address: UncheckedAccount<'info>,
+    /// CHECK: mint recipient allowlist PDA, may not exist
+    #[account(
+        mut,
+        seeds = [MINT_RECIPIENT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub mint_recipient_allowlist: UncheckedAccount<'info>,
+    /// CHECK: BTC payout allowlist PDA, may not exist
+    #[account(
+        mut,
+        seeds = [BTC_PAYOUT_ALLOWLIST_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub btc_payout_allowlist: UncheckedAccount<'info>,
+    #[account(address = controller_store.members @ FactoryError::InvalidMembersProgram)]
+    pub members_program: Program<'info, MembersProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resign_merchant_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ResignMerchant<'info>>) -> Result<()> {
+    require!(
+        !ctx.accounts.merchant_requests.has_pending_requests(),
+        FactoryError::PendingRequestsExist
+    );
+
+    // every address in the book must be closed, otherwise a later
+    // registration would collide with the leftover indices
+    let book_info = ctx.accounts.custodian_btc_address_book.to_account_info();
+    let address_count = if book_info.data_is_empty() {
+        0
+    } else {
+        CustodianBtcAddressBook::try_deserialize(&mut &book_info.try_borrow_data()?[..])?.address_count
+    };
+    require!(
+        ctx.remaining_accounts.len() == address_count as usize,
+        FactoryError::InvalidBatchAccounts
+    );
+    let merchant_key = ctx.accounts.payer.key();
+    let custodian = ctx.accounts.custodian.to_account_info();
+    for (index, info) in ctx.remaining_accounts.iter().enumerate() {
+        let custodian_btc_address = Account::<CustodianBtcDepositAddress>::try_from(info)?;
+        require!(
+            custodian_btc_address.merchant == merchant_key && custodian_btc_address.index as usize == index,
+            FactoryError::InvalidBatchAccounts
+        );
+        custodian_btc_address.close(custodian.clone())?;
+    }
+
+    // rent goes back to whoever paid for the account
+    let payer = ctx.accounts.payer.to_account_info();
+    close_if_exists(&book_info, &custodian)?;
+    close_if_exists(&ctx.accounts.merchant_btc_address, &payer)?;
+    close_if_exists(&ctx.accounts.mint_recipient_allowlist, &payer)?;
+    close_if_exists(&ctx.accounts.btc_payout_allowlist, &payer)?;
+
+    // close the merchant registration in members, the factory PDA vouches for the checks above
+    let cpi_program = ctx.accounts.members_program.to_account_info();
+    let cpi_accounts = members_cpi::accounts::ResignMerchant {
+        merchant: ctx.accounts.payer.to_account_info(),
+        factory_store: ctx.accounts.factory_store.to_account_info(),
+        members_store: ctx.accounts.members_store.to_account_info(),
+        owner: ctx.accounts.members_owner.to_account_info(),
+        merchant_info: ctx.accounts.merchant_info.to_account_info(),
+    };
+
+    // prepare PDA signature
+    let factory_seeds = &[FACTORY_SEED, &[ctx.accounts.factory_store.bump]];
+    let signer_seeds = &[&factory_seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    members_cpi::resign_merchant(cpi_ctx)?;
+
+    // refund the bookkeeping account
+    ctx.accounts
+        .merchant_requests
+        .close(ctx.accounts.payer.to_account_info())?;
+    Ok(())
+}
+
+// Closes a factory PDA that was created at some point, the same way Anchor's `close` does
+pub(crate) fn close_if_exists<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    if account.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*account.owner, crate::ID, FactoryError::Unauthorized);
+
+    let lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(FactoryError::MathOverflow)?;
+    **destination.try_borrow_mut_lamports()? = lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+    Ok(())
+}
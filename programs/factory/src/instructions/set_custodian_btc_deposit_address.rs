@@ -1,6 +1,8 @@
 use crate::errors::FactoryError;
-use crate::events::CustodianBtcDepositAddressSet;
-use crate::state::{CustodianBtcDepositAddress, FactoryStore};
+use crate::events::{CustodianBtcDepositAddressRetired, CustodianBtcDepositAddressSet};
+use crate::instructions::resign_merchant::close_if_exists;
+use crate::state::{CustodianBtcAddressBook, CustodianBtcDepositAddress, CustodianBtcDepositAddressV0, FactoryStore};
+use crate::CUSTODIAN_BTC_ADDRESS_BOOK_SEED;
 use crate::CUSTODIAN_BTC_ADDRESS_SEED;
 use crate::FACTORY_SEED;
 use anchor_lang::prelude::*;
@@ -46,10 +48,20 @@ pub struct SetCustodianBtcDepositAddress<'info> {
     #[account(
         init_if_needed,
         payer = payer,
+        space = 8 + CustodianBtcAddressBook::INIT_SPACE,
+        seeds = [CUSTODIAN_BTC_ADDRESS_BOOK_SEED, params.merchant.as_ref()],
+        bump
+    )]
+    pub custodian_btc_address_book: Account<'info, CustodianBtcAddressBook>,
+    // each new address gets the next index, previous ones stay on chain
+    #[account(
+        init,
+        payer = payer,
         space = 8 + CustodianBtcDepositAddress::INIT_SPACE,
         seeds = [
             CUSTODIAN_BTC_ADDRESS_SEED,
-            params.merchant.as_ref()
+            params.merchant.as_ref(),
+            &custodian_btc_address_book.address_count.to_le_bytes()
         ],
         bump
     )]
@@ -66,17 +78,206 @@ pub fn handler(
         FactoryError::InvalidBtcDepositAddress
     );
 
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // take the next index from the merchant's address book
+    let book = &mut ctx.accounts.custodian_btc_address_book;
+    let index = book.address_count;
+    book.merchant = params.merchant;
+    book.address_count = index.checked_add(1).ok_or(FactoryError::MathOverflow)?;
+    book.bump = ctx.bumps.custodian_btc_address_book;
+
     // update PDA account data
     let custodian_btc_address = &mut ctx.accounts.custodian_btc_address;
     custodian_btc_address.merchant = params.merchant;
+    custodian_btc_address.index = index;
     custodian_btc_address.btc_address = params.btc_deposit_address.clone();
+    custodian_btc_address.active = true;
+    custodian_btc_address.activated_at = timestamp;
+    custodian_btc_address.retired_at = 0;
     custodian_btc_address.bump = ctx.bumps.custodian_btc_address;
 
     // emit event
     emit!(CustodianBtcDepositAddressSet {
         merchant: params.merchant,
         custodian: ctx.accounts.payer.key(),
+        index,
         btc_deposit_address: params.btc_deposit_address.clone(),
+        activated_at: timestamp,
+    });
+
+    Ok(())
+}
+
+// ---- Retire Custodian BTC Deposit Address ----
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RetireCustodianBtcDepositAddressParams {
+    pub merchant: Pubkey,
+    pub index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RetireCustodianBtcDepositAddressParams)]
+pub struct RetireCustodianBtcDepositAddress<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, controller::ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian,
+    )]
+    pub members_store: Account<'info, members::MembersStore>,
+    #[account(
+        mut,
+        seeds = [
+            CUSTODIAN_BTC_ADDRESS_SEED,
+            params.merchant.as_ref(),
+            &params.index.to_le_bytes()
+        ],
+        bump = custodian_btc_address.bump
+    )]
+    pub custodian_btc_address: Account<'info, CustodianBtcDepositAddress>,
+}
+
+// The address is kept so requests for deposits made while it was active still pass
+pub fn retire_handler(
+    ctx: Context<RetireCustodianBtcDepositAddress>,
+    params: RetireCustodianBtcDepositAddressParams,
+) -> Result<()> {
+    let custodian_btc_address = &mut ctx.accounts.custodian_btc_address;
+    require!(custodian_btc_address.active, FactoryError::BtcDepositAddressRetired);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    custodian_btc_address.active = false;
+    custodian_btc_address.retired_at = timestamp;
+
+    emit!(CustodianBtcDepositAddressRetired {
+        merchant: params.merchant,
+        custodian: ctx.accounts.payer.key(),
+        index: params.index,
+        btc_deposit_address: custodian_btc_address.btc_address.clone(),
+        retired_at: timestamp,
+    });
+
+    Ok(())
+}
+
+// ---- Migrate Legacy Custodian BTC Deposit Address ----
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateCustodianBtcDepositAddressParams {
+    pub merchant: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateCustodianBtcDepositAddressParams)]
+pub struct MigrateCustodianBtcDepositAddress<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, controller::ControllerStore>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        seeds::program = controller_store.members,
+        constraint = members_store.is_custodian(&payer.key()) @ FactoryError::NotCustodian,
+    )]
+    pub members_store: Account<'info, members::MembersStore>,
+    /// CHECK: address in the original layout, checked in the handler and closed
+    #[account(
+        mut,
+        seeds = [CUSTODIAN_BTC_ADDRESS_SEED, params.merchant.as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub legacy_custodian_btc_address: UncheckedAccount<'info>,
+    // the legacy address becomes index 0, so the book must not exist yet
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CustodianBtcAddressBook::INIT_SPACE,
+        seeds = [CUSTODIAN_BTC_ADDRESS_BOOK_SEED, params.merchant.as_ref()],
+        bump
+    )]
+    pub custodian_btc_address_book: Account<'info, CustodianBtcAddressBook>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CustodianBtcDepositAddress::INIT_SPACE,
+        seeds = [
+            CUSTODIAN_BTC_ADDRESS_SEED,
+            params.merchant.as_ref(),
+            &0u32.to_le_bytes()
+        ],
+        bump
+    )]
+    pub custodian_btc_address: Account<'info, CustodianBtcDepositAddress>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_handler(
+    ctx: Context<MigrateCustodianBtcDepositAddress>,
+    params: MigrateCustodianBtcDepositAddressParams,
+) -> Result<()> {
+    // the original layout shares the discriminator of the indexed one
+    let legacy_info = ctx.accounts.legacy_custodian_btc_address.to_account_info();
+    let legacy = {
+        let data = legacy_info.try_borrow_data()?;
+        require!(
+            data.len() == 8 + CustodianBtcDepositAddressV0::INIT_SPACE
+                && data[..8] == *CustodianBtcDepositAddress::DISCRIMINATOR,
+            FactoryError::NotLegacyBtcDepositAddress
+        );
+        CustodianBtcDepositAddressV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.merchant, params.merchant, FactoryError::NotLegacyBtcDepositAddress);
+
+    let book = &mut ctx.accounts.custodian_btc_address_book;
+    book.merchant = params.merchant;
+    book.address_count = 1;
+    book.bump = ctx.bumps.custodian_btc_address_book;
+
+    // the address was in use before it got an activation time, deposits
+    // made to it before the migration stay claimable
+    let custodian_btc_address = &mut ctx.accounts.custodian_btc_address;
+    custodian_btc_address.merchant = params.merchant;
+    custodian_btc_address.index = 0;
+    custodian_btc_address.btc_address = legacy.btc_address.clone();
+    custodian_btc_address.active = true;
+    custodian_btc_address.activated_at = 0;
+    custodian_btc_address.retired_at = 0;
+    custodian_btc_address.bump = ctx.bumps.custodian_btc_address;
+
+    // the custodian paid for the legacy account and gets its rent back
+    close_if_exists(&legacy_info, &ctx.accounts.payer.to_account_info())?;
+
+    emit!(CustodianBtcDepositAddressSet {
+        merchant: params.merchant,
+        custodian: ctx.accounts.payer.key(),
+        index: 0,
+        btc_deposit_address: legacy.btc_address,
+        activated_at: 0,
     });
 
     Ok(())
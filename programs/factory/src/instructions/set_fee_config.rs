@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::TokenAccount,
+};
+use crate::errors::FactoryError;
+use crate::events::{FeeConfigCanceled, FeeConfigQueued, FeeConfigSet};
+use crate::state::{FactoryStore, PendingFeeConfig};
+use crate::FACTORY_SEED;
+use controller::ControllerStore;
+use controller::CONTROLLER_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFeeConfigParams {
+    pub mint_fee_bps: u16,
+    pub burn_fee_bps: u16,
+    pub min_fee: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+        has_one = owner @ FactoryError::Unauthorized,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+    // The controller mints fees through associated token accounts only,
+    // omit to disable the fees
+    #[account(
+        token::mint = controller_store.token_mint,
+        constraint = fee_recipient.key() == get_associated_token_address_with_program_id(
+            &fee_recipient.owner,
+            &controller_store.token_mint,
+            fee_recipient.to_account_info().owner,
+        ) @ FactoryError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<SetFeeConfig>, params: SetFeeConfigParams) -> Result<()> {
+    FactoryStore::validate_fee_bps(params.mint_fee_bps, params.burn_fee_bps)?;
+
+    let controller_store = &ctx.accounts.controller_store;
+    let config = PendingFeeConfig {
+        mint_fee_bps: params.mint_fee_bps,
+        burn_fee_bps: params.burn_fee_bps,
+        min_fee: params.min_fee,
+        fee_recipient: ctx
+            .accounts
+            .fee_recipient
+            .as_ref()
+            .map_or(Pubkey::default(), |fee_recipient| fee_recipient.key()),
+        eta: Clock::get()?.unix_timestamp + controller_store.timelock_delay,
+    };
+
+    // Once the controller is timelocked fee changes wait out the same delay,
+    // a new change replaces the queued one
+    let factory_store = &mut ctx.accounts.factory_store;
+    if controller_store.is_timelocked() {
+        emit!(FeeConfigQueued {
+            mint_fee_bps: config.mint_fee_bps,
+            burn_fee_bps: config.burn_fee_bps,
+            min_fee: config.min_fee,
+            fee_recipient: config.fee_recipient,
+            eta: config.eta,
+        });
+        factory_store.pending_fee_config = Some(config);
+        return Ok(());
+    }
+
+    factory_store.apply_fee_config(&config);
+    emit_fee_config_set(factory_store);
+    Ok(())
+}
+
+// ---- Apply a queued fee configuration ----
+
+#[derive(Accounts)]
+pub struct ApplyFeeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+}
+
+pub fn apply_handler(ctx: Context<ApplyFeeConfig>) -> Result<()> {
+    let factory_store = &mut ctx.accounts.factory_store;
+    let config = factory_store
+        .pending_fee_config
+        .clone()
+        .ok_or(FactoryError::NoPendingFeeConfig)?;
+    require!(
+        Clock::get()?.unix_timestamp >= config.eta,
+        FactoryError::FeeConfigNotReady
+    );
+
+    factory_store.apply_fee_config(&config);
+    emit_fee_config_set(factory_store);
+    Ok(())
+}
+
+// ---- Cancel a queued fee configuration ----
+
+#[derive(Accounts)]
+pub struct CancelFeeConfig<'info> {
+    // Controller guardian or owner
+    #[account(
+        constraint = authority.key() == controller_store.guardian
+            || authority.key() == controller_store.owner @ FactoryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory_store.bump
+    )]
+    pub factory_store: Account<'info, FactoryStore>,
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump = controller_store.bump,
+        seeds::program = factory_store.controller,
+    )]
+    pub controller_store: Account<'info, ControllerStore>,
+}
+
+pub fn cancel_handler(ctx: Context<CancelFeeConfig>) -> Result<()> {
+    let factory_store = &mut ctx.accounts.factory_store;
+    require!(
+        factory_store.pending_fee_config.is_some(),
+        FactoryError::NoPendingFeeConfig
+    );
+    factory_store.pending_fee_config = None;
+
+    emit!(FeeConfigCanceled {
+        canceled_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+fn emit_fee_config_set(factory_store: &FactoryStore) {
+    emit!(FeeConfigSet {
+        mint_fee_bps: factory_store.mint_fee_bps,
+        burn_fee_bps: factory_store.burn_fee_bps,
+        min_fee: factory_store.min_fee,
+        fee_recipient: factory_store.fee_recipient,
+    });
+}
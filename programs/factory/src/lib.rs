@@ -10,9 +10,14 @@ declare_id!("D29DJUN28bmSbMwQfeaBK2792HeSrDEsBg2uouD6q3Kr");
 // Seeds constants
 pub const FACTORY_SEED: &[u8] = b"factory";
 pub const CUSTODIAN_BTC_ADDRESS_SEED: &[u8] = b"custodian_btc_address";
+pub const CUSTODIAN_BTC_ADDRESS_BOOK_SEED: &[u8] = b"custodian_btc_address_book";
 pub const MERCHANT_BTC_ADDRESS_SEED: &[u8] = b"merchant_btc_address";
 pub const MINT_REQUEST_SEED: &[u8] = b"mint_request";
 pub const BURN_REQUEST_SEED: &[u8] = b"burn_request";
+pub const MERCHANT_REQUESTS_SEED: &[u8] = b"merchant_requests";
+pub const MINT_RECIPIENT_ALLOWLIST_SEED: &[u8] = b"mint_recipient_allowlist";
+pub const BTC_PAYOUT_ALLOWLIST_SEED: &[u8] = b"btc_payout_allowlist";
+pub const BURN_PAYOUTS_SEED: &[u8] = b"burn_payouts";
 
 #[program]
 pub mod factory {
@@ -22,7 +27,19 @@ pub mod factory {
         initialize::handler(ctx, params)
     }
 
-    /// Custodian sets the BTC deposit address for a merchant (mint process)
+    /// Controller owner rewrites a factory store created with the original layout,
+    /// protocol fees start disabled
+    pub fn migrate_factory_store(ctx: Context<MigrateFactoryStore>) -> Result<()> {
+        migrate_factory_store::handler(ctx)
+    }
+
+    /// Custodian rewrites a request opened with the original layout, the mint
+    /// recipient is the requester and its hash is recomputed
+    pub fn migrate_request(ctx: Context<MigrateRequest>, params: MigrateRequestParams) -> Result<()> {
+        migrate_request::handler(ctx, params)
+    }
+
+    /// Custodian activates a new BTC deposit address for a merchant (mint process)
     pub fn set_custodian_btc_deposit_address(
         ctx: Context<SetCustodianBtcDepositAddress>,
         params: SetCustodianBtcDepositAddressParams,
@@ -30,6 +47,23 @@ pub mod factory {
         set_custodian_btc_deposit_address::handler(ctx, params)
     }
 
+    /// Custodian retires one of a merchant's BTC deposit addresses
+    pub fn retire_custodian_btc_deposit_address(
+        ctx: Context<RetireCustodianBtcDepositAddress>,
+        params: RetireCustodianBtcDepositAddressParams,
+    ) -> Result<()> {
+        set_custodian_btc_deposit_address::retire_handler(ctx, params)
+    }
+
+    /// Custodian moves a merchant's BTC deposit address from the original single
+    /// address layout to index 0 of its address book
+    pub fn migrate_custodian_btc_deposit_address(
+        ctx: Context<MigrateCustodianBtcDepositAddress>,
+        params: MigrateCustodianBtcDepositAddressParams,
+    ) -> Result<()> {
+        set_custodian_btc_deposit_address::migrate_handler(ctx, params)
+    }
+
     /// Merchant sets their own BTC deposit address (burn process)
     pub fn set_merchant_btc_deposit_address(
         ctx: Context<SetMerchantBtcDepositAddress>,
@@ -38,6 +72,38 @@ pub mod factory {
         set_merchant_btc_deposit_address::handler(ctx, params)
     }
 
+    /// Controller owner configures the protocol fees charged on mint and burn,
+    /// queued for the controller timelock delay once the timelock is enabled
+    pub fn set_fee_config(ctx: Context<SetFeeConfig>, params: SetFeeConfigParams) -> Result<()> {
+        set_fee_config::handler(ctx, params)
+    }
+
+    /// Anyone applies a queued fee configuration once its ETA has passed
+    pub fn apply_fee_config(ctx: Context<ApplyFeeConfig>) -> Result<()> {
+        set_fee_config::apply_handler(ctx)
+    }
+
+    /// Controller owner or guardian drops a queued fee configuration
+    pub fn cancel_fee_config(ctx: Context<CancelFeeConfig>) -> Result<()> {
+        set_fee_config::cancel_handler(ctx)
+    }
+
+    /// Merchant allows a wallet to receive its mints
+    pub fn add_mint_recipient(
+        ctx: Context<AddMintRecipient>,
+        params: MintRecipientParams,
+    ) -> Result<()> {
+        mint_recipients::add_mint_recipient_handler(ctx, params)
+    }
+
+    /// Merchant removes a wallet from its mint recipients
+    pub fn remove_mint_recipient(
+        ctx: Context<RemoveMintRecipient>,
+        params: MintRecipientParams,
+    ) -> Result<()> {
+        mint_recipients::remove_mint_recipient_handler(ctx, params)
+    }
+
     /// Merchant adds a new mint request
     pub fn add_mint_request(ctx: Context<AddMintRequest>, params: MintParams) -> Result<()> {
         mint_requests::add_mint_request_handler(ctx, params)
@@ -59,6 +125,14 @@ pub mod factory {
         mint_requests::confirm_mint_request_handler(ctx, params)
     }
 
+    /// Custodian confirms a mint request for less than the requested amount, stating the reason
+    pub fn confirm_mint_request_partial(
+        ctx: Context<ConfirmMintRequestPartial>,
+        params: ConfirmMintPartialParams,
+    ) -> Result<()> {
+        mint_requests::confirm_mint_request_partial_handler(ctx, params)
+    }
+
     /// Custodian rejects mint request
     pub fn reject_mint_request(
         ctx: Context<RejectMintRequest>,
@@ -67,8 +141,45 @@ pub mod factory {
         mint_requests::reject_mint_request_handler(ctx, params)
     }
 
-    /// Merchant initiates a burn request
-    pub fn burn(ctx: Context<Burn>, params: BurnParams) -> Result<()> {
+    /// Custodian confirms several mint requests at once, remaining accounts carry the request
+    /// PDA, merchant requests PDA, recipient ATA and recipient denylist PDA of each request
+    pub fn confirm_mint_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmMintRequests<'info>>,
+        params: BatchMintParams,
+    ) -> Result<()> {
+        batch_requests::confirm_mint_requests_handler(ctx, params)
+    }
+
+    /// Custodian rejects several mint requests at once, remaining accounts carry the request
+    /// PDA and merchant requests PDA of each request
+    pub fn reject_mint_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RejectMintRequests<'info>>,
+        params: BatchMintParams,
+    ) -> Result<()> {
+        batch_requests::reject_mint_requests_handler(ctx, params)
+    }
+
+    /// Merchant allows a BTC address to receive its burn payouts
+    pub fn add_btc_payout_address(
+        ctx: Context<AddBtcPayoutAddress>,
+        params: BtcPayoutAddressParams,
+    ) -> Result<()> {
+        btc_payout_addresses::add_btc_payout_address_handler(ctx, params)
+    }
+
+    /// Merchant removes a BTC address from its burn payout addresses
+    pub fn remove_btc_payout_address(
+        ctx: Context<RemoveBtcPayoutAddress>,
+        params: BtcPayoutAddressParams,
+    ) -> Result<()> {
+        btc_payout_addresses::remove_btc_payout_address_handler(ctx, params)
+    }
+
+    /// Merchant initiates a burn request, remaining accounts carry the transfer hook accounts for the fee transfer
+    pub fn burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+        params: BurnParams,
+    ) -> Result<()> {
         burn::burn_handler(ctx, params)
     }
 
@@ -79,4 +190,26 @@ pub mod factory {
     ) -> Result<()> {
         burn::confirm_burn_request_handler(ctx, params)
     }
+
+    /// Custodian records one of several BTC payouts for a burn request, approving it once the burn is covered
+    pub fn record_burn_payout(
+        ctx: Context<RecordBurnPayout>,
+        params: ConfirmBurnParams,
+    ) -> Result<()> {
+        burn::record_burn_payout_handler(ctx, params)
+    }
+
+    /// Custodian confirms several burn requests paid out by one BTC transaction, one output each,
+    /// remaining accounts carry the request PDA and merchant requests PDA of each request
+    pub fn confirm_burn_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmBurnRequests<'info>>,
+        params: BatchBurnParams,
+    ) -> Result<()> {
+        batch_requests::confirm_burn_requests_handler(ctx, params)
+    }
+
+    /// Merchant leaves the system, closing its factory accounts and its members registration
+    pub fn resign_merchant<'info>(ctx: Context<'_, '_, 'info, 'info, ResignMerchant<'info>>) -> Result<()> {
+        resign_merchant::resign_merchant_handler(ctx)
+    }
 }
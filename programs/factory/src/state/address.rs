@@ -1,9 +1,48 @@
 use anchor_lang::prelude::*;
 
-// BTC deposit address set by custodian for merchant
+// Deposits to a retired address must be claimed within this period
+pub const RETIRED_ADDRESS_CLAIM_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+// Index of the BTC deposit addresses the custodian assigned to a merchant
+#[account]
+#[derive(InitSpace)]
+pub struct CustodianBtcAddressBook {
+    pub merchant: Pubkey,
+    pub address_count: u32, // index of the next address
+    pub bump: u8,
+}
+
+// BTC deposit address set by custodian for merchant, one account per index
 #[account]
 #[derive(InitSpace)]
 pub struct CustodianBtcDepositAddress {
+    pub merchant: Pubkey,
+    pub index: u32,
+    #[max_len(100)]
+    pub btc_address: String,
+    pub active: bool,
+    pub activated_at: i64,
+    pub retired_at: i64, // 0 while active
+    pub bump: u8,
+}
+
+impl CustodianBtcDepositAddress {
+    // Deposits made before retirement stay valid for requests filed afterwards
+    pub fn was_active_at(&self, timestamp: i64) -> bool {
+        self.activated_at <= timestamp && (self.active || timestamp < self.retired_at)
+    }
+
+    // A retired address only takes requests for a limited time, which bounds
+    // how long a backdated deposit can be claimed against it
+    pub fn accepts_claims_at(&self, now: i64) -> bool {
+        self.active || now <= self.retired_at.saturating_add(RETIRED_ADDRESS_CLAIM_PERIOD)
+    }
+}
+
+// Custodian BTC deposit address in the original single address layout,
+// stored under [CUSTODIAN_BTC_ADDRESS_SEED, merchant]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct CustodianBtcDepositAddressV0 {
     pub merchant: Pubkey,
     #[max_len(100)]
     pub btc_address: String,
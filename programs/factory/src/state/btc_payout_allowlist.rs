@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_BTC_PAYOUT_ADDRESSES: usize = 16;
+pub const MAX_BTC_ADDRESS_LEN: usize = 100;
+
+// BTC addresses a merchant can have its burns paid out to, e.g. the
+// addresses of its end clients
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct BtcPayoutAllowlist {
+    pub merchant: Pubkey,
+    #[max_len(MAX_BTC_PAYOUT_ADDRESSES, MAX_BTC_ADDRESS_LEN)]
+    pub btc_addresses: Vec<String>,
+    pub bump: u8,
+}
+
+impl BtcPayoutAllowlist {
+    pub fn is_allowed(&self, btc_address: &str) -> bool {
+        self.btc_addresses.iter().any(|address| address == btc_address)
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::errors::FactoryError;
+
+pub const MAX_BURN_PAYOUTS: usize = 8;
+
+// BTC transactions settling a burn paid out in several parts, created with
+// the first partial payout so single payout burns don't carry the list
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct BurnPayouts {
+    pub nonce: u64, // burn request nonce
+    #[max_len(MAX_BURN_PAYOUTS)]
+    pub payouts: Vec<BtcPayout>,
+    pub bump: u8,
+}
+
+// One BTC transaction paying out (part of) a burn
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Debug)]
+pub struct BtcPayout {
+    #[max_len(64)]
+    pub btc_txid: String,
+    pub btc_amount_sent: u64,
+    pub btc_network_fee: u64,
+    pub btc_vout: Option<u32>, // output index, when the transaction pays out several burns
+}
+
+impl BurnPayouts {
+    pub fn record(&mut self, payout: BtcPayout) -> Result<()> {
+        require!(self.payouts.len() < MAX_BURN_PAYOUTS, FactoryError::TooManyPayouts);
+        // a transaction output can only be counted once, a transaction without
+        // an output index stands for all of its outputs
+        require!(
+            !self.payouts.iter().any(|recorded| {
+                recorded.btc_txid == payout.btc_txid
+                    && (recorded.btc_vout.is_none()
+                        || payout.btc_vout.is_none()
+                        || recorded.btc_vout == payout.btc_vout)
+            }),
+            FactoryError::DuplicateBtcPayout
+        );
+        self.payouts.push(payout);
+        Ok(())
+    }
+}
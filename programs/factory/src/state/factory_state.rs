@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use members::{MerchantTier, MAX_FEE_BPS};
+use crate::state::RequestType;
+use crate::errors::FactoryError;
 
 #[account]
 #[derive(InitSpace)]
@@ -7,4 +10,73 @@ pub struct FactoryStore {
     pub burn_request_counter: u64,
     pub controller: Pubkey,
     pub bump: u8,
+    pub mint_fee_bps: u16,          // protocol fee charged on confirmed mints
+    pub burn_fee_bps: u16,          // protocol fee charged on burns
+    pub min_fee: u64,               // flat minimum fee, in token base units
+    pub fee_recipient: Pubkey,      // treasury token account, fees are disabled while unset
+    pub pending_fee_config: Option<PendingFeeConfig>, // fee change waiting out the controller timelock delay
+}
+
+// Layout deployed before the protocol fees were added, read by migrate_factory_store
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug)]
+pub struct FactoryStoreV0 {
+    pub mint_request_counter: u64,
+    pub burn_request_counter: u64,
+    pub controller: Pubkey,
+    pub bump: u8,
+}
+
+// Fee configuration queued while the controller timelock is enabled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Debug)]
+pub struct PendingFeeConfig {
+    pub mint_fee_bps: u16,
+    pub burn_fee_bps: u16,
+    pub min_fee: u64,
+    pub fee_recipient: Pubkey,
+    pub eta: i64, // earliest time the change can be applied
+}
+
+impl FactoryStore {
+    pub fn validate_fee_bps(mint_fee_bps: u16, burn_fee_bps: u16) -> Result<()> {
+        require!(
+            mint_fee_bps <= MAX_FEE_BPS && burn_fee_bps <= MAX_FEE_BPS,
+            FactoryError::InvalidFeeConfig
+        );
+        Ok(())
+    }
+
+    pub fn apply_fee_config(&mut self, config: &PendingFeeConfig) {
+        self.mint_fee_bps = config.mint_fee_bps;
+        self.burn_fee_bps = config.burn_fee_bps;
+        self.min_fee = config.min_fee;
+        self.fee_recipient = config.fee_recipient;
+        self.pending_fee_config = None;
+    }
+
+    pub fn fees_enabled(&self) -> bool {
+        self.fee_recipient != Pubkey::default()
+    }
+
+    // Fee rate for a merchant's request, its tier's rate replaces the global one
+    pub fn fee_bps_for(&self, request_type: &RequestType, merchant_tier: Option<&MerchantTier>) -> u16 {
+        match (merchant_tier, request_type) {
+            (Some(tier), _) => tier.fee_bps,
+            (None, RequestType::Mint) => self.mint_fee_bps,
+            (None, RequestType::Burn) => self.burn_fee_bps,
+        }
+    }
+
+    // Protocol fee on `amount`: the proportional fee, raised to the flat minimum
+    pub fn calculate_fee(&self, amount: u64, fee_bps: u16) -> Result<u64> {
+        if !self.fees_enabled() {
+            return Ok(0);
+        }
+        let proportional = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(FactoryError::MathOverflow)?
+            / MAX_FEE_BPS as u128;
+        let fee = (proportional as u64).max(self.min_fee);
+        require!(fee < amount, FactoryError::FeeExceedsAmount);
+        Ok(fee)
+    }
 }
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use members::{MerchantInfo, MerchantTier};
+use crate::errors::FactoryError;
+use crate::state::{RequestAccount, RequestType};
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// Open request bookkeeping per merchant, so a merchant can only resign once
+// every request it initiated has been settled, and tier daily caps can be enforced
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct MerchantRequests {
+    pub merchant: Pubkey,
+    pub pending_mint_requests: u64,
+    pub pending_burn_requests: u64,
+    pub current_day: i64,               // day index (unix timestamp / SECONDS_PER_DAY) of the daily amounts
+    pub daily_mint_amount: u64,         // mint amount requested during current_day and not released
+    pub daily_burn_amount: u64,         // burn amount requested during current_day
+    pub bump: u8,
+}
+
+impl MerchantRequests {
+    // Record a new request, enforcing the merchant tier policy if there is one
+    pub fn open_request(
+        &mut self,
+        request_type: &RequestType,
+        amount: u64,
+        timestamp: i64,
+        merchant_tier: Option<&MerchantTier>,
+    ) -> Result<()> {
+        let day = timestamp / SECONDS_PER_DAY;
+        if day != self.current_day {
+            self.current_day = day;
+            self.daily_mint_amount = 0;
+            self.daily_burn_amount = 0;
+        }
+        // mints and burns are capped separately
+        let daily_amount = self.daily_amount(request_type);
+        *daily_amount = daily_amount.checked_add(amount).ok_or(FactoryError::MathOverflow)?;
+        let daily_amount = *daily_amount;
+
+        if let Some(tier) = merchant_tier {
+            if *request_type == RequestType::Burn {
+                require!(tier.burns_allowed, FactoryError::BurnsNotAllowed);
+            }
+            require!(
+                amount <= tier.max_request_amount,
+                FactoryError::TierRequestLimitExceeded
+            );
+            require!(
+                daily_amount <= tier.daily_cap,
+                FactoryError::TierDailyCapExceeded
+            );
+        }
+
+        self.track_request(request_type)
+    }
+
+    // Count a pending request, also used to backfill requests opened before the
+    // counters existed
+    pub fn track_request(&mut self, request_type: &RequestType) -> Result<()> {
+        let counter = self.pending_counter(request_type);
+        *counter = counter.checked_add(1).ok_or(FactoryError::MathOverflow)?;
+        Ok(())
+    }
+
+    // A request opened before the counters existed may not have been counted,
+    // settling it must not fail. The part of a mint request that was not minted
+    // (canceled, rejected or partially approved) no longer counts towards the
+    // daily cap of the day it was opened.
+    pub fn settle_request(&mut self, request: &RequestAccount) -> Result<()> {
+        let counter = self.pending_counter(&request.request_type);
+        *counter = counter.saturating_sub(1);
+
+        if request.request_type == RequestType::Mint
+            && request.timestamp / SECONDS_PER_DAY == self.current_day
+        {
+            let not_minted = request.amount.saturating_sub(request.confirmed_amount);
+            self.daily_mint_amount = self.daily_mint_amount.saturating_sub(not_minted);
+        }
+        Ok(())
+    }
+
+    pub fn has_pending_requests(&self) -> bool {
+        self.pending_mint_requests > 0 || self.pending_burn_requests > 0
+    }
+
+    fn pending_counter(&mut self, request_type: &RequestType) -> &mut u64 {
+        match request_type {
+            RequestType::Mint => &mut self.pending_mint_requests,
+            RequestType::Burn => &mut self.pending_burn_requests,
+        }
+    }
+
+    fn daily_amount(&mut self, request_type: &RequestType) -> &mut u64 {
+        match request_type {
+            RequestType::Mint => &mut self.daily_mint_amount,
+            RequestType::Burn => &mut self.daily_burn_amount,
+        }
+    }
+}
+
+// Resolve the tier policy that applies to a merchant. The tier account is only
+// required when the merchant is assigned to one.
+pub fn merchant_tier_policy<'a>(
+    merchant_info: &MerchantInfo,
+    merchant_tier: &'a Option<Account<MerchantTier>>,
+) -> Result<Option<&'a MerchantTier>> {
+    match merchant_tier {
+        Some(tier) => {
+            require_keys_eq!(tier.key(), merchant_info.tier, FactoryError::InvalidMerchantTier);
+            Ok(Some(tier))
+        }
+        None => {
+            require!(!merchant_info.has_tier(), FactoryError::InvalidMerchantTier);
+            Ok(None)
+        }
+    }
+}
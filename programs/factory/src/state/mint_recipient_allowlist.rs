@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_MINT_RECIPIENTS: usize = 16;
+
+// Wallets a merchant can have its mints delivered to instead of its own,
+// e.g. settlement or client wallets
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct MintRecipientAllowlist {
+    pub merchant: Pubkey,
+    #[max_len(MAX_MINT_RECIPIENTS)]
+    pub recipients: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl MintRecipientAllowlist {
+    pub fn is_allowed(&self, recipient: &Pubkey) -> bool {
+        self.recipients.contains(recipient)
+    }
+}
@@ -1,7 +1,15 @@
 pub mod factory_state;
 pub mod request;
 pub mod address;
+pub mod merchant_requests;
+pub mod mint_recipient_allowlist;
+pub mod btc_payout_allowlist;
+pub mod burn_payouts;
 
 pub use factory_state::*;
 pub use request::*; 
 pub use address::*;
+pub use merchant_requests::*;
+pub use mint_recipient_allowlist::*;
+pub use btc_payout_allowlist::*;
+pub use burn_payouts::*;
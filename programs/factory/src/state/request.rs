@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak::hashv;
+use crate::errors::FactoryError;
+use crate::{BURN_REQUEST_SEED, MINT_REQUEST_SEED};
+
+pub const MAX_ADJUSTMENT_REASON_LEN: usize = 200;
 
 #[account]
 #[derive(InitSpace, Debug)]
@@ -11,6 +15,31 @@ pub struct RequestAccount {
     pub btc_deposit_address: String,
     #[max_len(64)]
     pub btc_txid: String,
+    pub btc_deposit_timestamp: i64, // when the merchant sent the BTC, for the custodian to verify
+    pub nonce: u64,
+    pub timestamp: i64,
+    pub status: RequestStatus,
+    pub hash: [u8; 32],
+    pub bump: u8,
+    pub btc_amount_sent: u64, // total burn payout that reached the merchant
+    pub btc_network_fee: u64, // total BTC miner fee paid by the custodian for the payouts
+    pub confirmed_amount: u64, // amount actually minted, below `amount` when partially approved
+    pub btc_vout: Option<u32>, // output of the final burn payout, when its transaction pays out several burns
+    pub recipient: Pubkey, // mint recipient, the requester unless the merchant designated another
+    pub fee_bps: u16, // protocol fee rate applying to the request, fixed when it was opened
+}
+
+// Layout deployed before the deposit timestamp, the BTC payout totals, the mint
+// recipient and the fee rate were added, read by migrate_request
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug)]
+pub struct RequestAccountV0 {
+    pub request_type: RequestType,
+    pub requester: Pubkey,
+    pub amount: u64,
+    #[max_len(100)]
+    pub btc_deposit_address: String,
+    #[max_len(64)]
+    pub btc_txid: String,
     pub nonce: u64,
     pub timestamp: i64,
     pub status: RequestStatus,
@@ -24,12 +53,23 @@ pub enum RequestType {
     Burn,
 }
 
+impl RequestType {
+    // Seed of the request PDAs of this type, followed by the nonce
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            RequestType::Mint => MINT_REQUEST_SEED,
+            RequestType::Burn => BURN_REQUEST_SEED,
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Debug)]
 pub enum RequestStatus {
     Pending,
     Canceled,
     Approved,
     Rejected,
+    PartiallyApproved,
 }
 
 impl RequestAccount {
@@ -39,8 +79,41 @@ impl RequestAccount {
         data.extend_from_slice(&self.amount.to_le_bytes());
         data.extend_from_slice(self.btc_deposit_address.as_bytes());
         data.extend_from_slice(self.btc_txid.as_bytes());
+        data.extend_from_slice(&self.btc_deposit_timestamp.to_le_bytes());
         data.extend_from_slice(&self.nonce.to_le_bytes());
         data.extend_from_slice(&self.timestamp.to_le_bytes());
+        data.extend_from_slice(self.recipient.as_ref());
         hashv(&[&data]).0
     }
+
+    // Add a BTC payout to the burn totals, the payouts and their network fees
+    // together can't exceed the burned amount
+    pub fn record_payout(&mut self, btc_amount_sent: u64, btc_network_fee: u64) -> Result<()> {
+        let btc_amount_sent_total = self
+            .btc_amount_sent
+            .checked_add(btc_amount_sent)
+            .ok_or(FactoryError::MathOverflow)?;
+        let btc_network_fee_total = self
+            .btc_network_fee
+            .checked_add(btc_network_fee)
+            .ok_or(FactoryError::MathOverflow)?;
+        let btc_total = btc_amount_sent_total
+            .checked_add(btc_network_fee_total)
+            .ok_or(FactoryError::MathOverflow)?;
+        require!(btc_total <= self.amount, FactoryError::InvalidBtcPayout);
+
+        self.btc_amount_sent = btc_amount_sent_total;
+        self.btc_network_fee = btc_network_fee_total;
+        Ok(())
+    }
+
+    // Whether partial payouts were recorded, listed in the burn payouts PDA
+    pub fn has_partial_payouts(&self) -> bool {
+        self.btc_amount_sent > 0
+    }
+
+    // Whether the recorded payouts and network fees cover the burned amount
+    pub fn is_burn_covered(&self) -> bool {
+        self.btc_amount_sent.saturating_add(self.btc_network_fee) >= self.amount
+    }
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use multisig_core::MultisigError;
 
 #[error_code]
 pub enum MembersError {
@@ -26,8 +27,54 @@ pub enum MembersError {
     NoPendingOwner,
     #[msg("Invalid pending owner")]
     InvalidPendingOwner,
+    #[msg("Invalid validity period")]
+    InvalidValidityPeriod,
+    #[msg("Nomination expired")]
+    NominationExpired,
     #[msg("Invalid program ID")]
     InvalidProgramId,
     #[msg("Invalid operation")]
     InvalidOperation,
+    #[msg("Invalid compliance expiry")]
+    InvalidComplianceExpiry,
+    #[msg("Invalid tier name")]
+    InvalidTierName,
+    #[msg("Invalid tier policy")]
+    InvalidTierPolicy,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Duplicate merchant info account")]
+    DuplicateMerchantInfo,
+    #[msg("Invalid multisig signers")]
+    InvalidMultisigSigners,
+    #[msg("Invalid multisig threshold")]
+    InvalidMultisigThreshold,
+    #[msg("Not a multisig signer")]
+    NotMultisigSigner,
+    #[msg("Invalid proposal")]
+    InvalidProposal,
+    #[msg("Proposal already approved by signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal threshold not met")]
+    ProposalThresholdNotMet,
+    #[msg("Proposal is stale after a signer set change")]
+    StaleProposal,
+    #[msg("Members store is already in the current layout")]
+    AlreadyMigrated,
+    #[msg("Merchant count does not match the merchant info accounts")]
+    MerchantCountMismatch,
+}
+
+impl From<MultisigError> for MembersError {
+    fn from(error: MultisigError) -> Self {
+        match error {
+            MultisigError::InvalidSigners => MembersError::InvalidMultisigSigners,
+            MultisigError::InvalidThreshold => MembersError::InvalidMultisigThreshold,
+            MultisigError::InvalidProposal => MembersError::InvalidProposal,
+            MultisigError::AlreadyApproved => MembersError::ProposalAlreadyApproved,
+            MultisigError::ThresholdNotMet => MembersError::ProposalThresholdNotMet,
+            MultisigError::StaleProposal => MembersError::StaleProposal,
+            MultisigError::MathOverflow => MembersError::MathOverflow,
+        }
+    }
 }
@@ -15,6 +15,60 @@ pub struct MerchantRemove {
     pub merchant: Pubkey,
 }
 
+#[event]
+pub struct MerchantResigned {
+    pub merchant: Pubkey,
+}
+
+#[event]
+pub struct FactorySet {
+    pub factory: Pubkey,
+}
+
+#[event]
+pub struct ComplianceAuthoritySet {
+    pub compliance_authority: Pubkey,
+}
+
+#[event]
+pub struct MerchantComplianceSet {
+    pub merchant: Pubkey,
+    pub compliance_valid_until: i64,
+}
+
+#[event]
+pub struct MerchantComplianceExpiring {
+    pub merchant: Pubkey,
+    pub compliance_valid_until: i64,
+    pub seconds_remaining: i64,
+}
+
+#[event]
+pub struct MerchantTierCreated {
+    pub tier: Pubkey,
+    pub name: String,
+    pub max_request_amount: u64,
+    pub daily_cap: u64,
+    pub fee_bps: u16,
+    pub burns_allowed: bool,
+}
+
+#[event]
+pub struct MerchantTierUpdated {
+    pub tier: Pubkey,
+    pub name: String,
+    pub max_request_amount: u64,
+    pub daily_cap: u64,
+    pub fee_bps: u16,
+    pub burns_allowed: bool,
+}
+
+#[event]
+pub struct MerchantTierAssigned {
+    pub merchant: Pubkey,
+    pub tier: Pubkey,
+}
+
 #[event]
 pub struct MembersInitialized {
     pub owner: Pubkey,
@@ -25,6 +79,7 @@ pub struct MembersInitialized {
 pub struct OwnershipTransferStarted {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
+    pub deadline: i64,
 }
 
 #[event]
@@ -33,7 +88,88 @@ pub struct OwnershipTransferred {
     pub new_owner: Pubkey,
 }
 
+#[event]
+pub struct OwnershipTransferCanceled {
+    pub owner: Pubkey,
+    pub canceled_owner: Pubkey,
+}
+
+#[event]
+pub struct MembersVerified {
+    pub merchant_count: u64,
+    pub verified_count: u64,
+    pub consistent: bool,
+}
+
 #[event]
 pub struct MerchantsResized {
     pub new_capacity: u16,
 }
+
+#[event]
+pub struct MultisigCreated {
+    pub authority: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigSignersSet {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub generation: u32,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub generation: u32,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub id: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalCanceled {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct AddressDenylisted {
+    pub address: Pubkey,
+    pub reason_code: u16,
+    pub timestamp: i64,
+    pub compliance_authority: Pubkey,
+}
+
+#[event]
+pub struct AddressRemovedFromDenylist {
+    pub address: Pubkey,
+    pub reason_code: u16,
+    pub timestamp: i64,
+    pub compliance_authority: Pubkey,
+}
+
+#[event]
+pub struct MembersStoreMigrated {
+    pub owner: Pubkey,
+    pub merchant_count: u64,
+}
+
+#[event]
+pub struct MerchantInfoMigrated {
+    pub merchant: Pubkey,
+}
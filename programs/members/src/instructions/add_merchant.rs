@@ -43,10 +43,16 @@ pub fn add_merchant(ctx: Context<AddMerchant>, params: AddMerchantParams) -> Res
     // initialize merchant info PDA
     let merchant_info = &mut ctx.accounts.merchant_info;
     merchant_info.merchant = params.merchant;
+    // not compliant until the compliance authority calls set_merchant_compliance
+    merchant_info.compliance_valid_until = 0;
+    merchant_info.tier = Pubkey::default();
     merchant_info.bump = ctx.bumps.merchant_info;
 
     // update merchant count
-    members_store.merchant_count = members_store.merchant_count.saturating_add(1);
+    members_store.merchant_count = members_store
+        .merchant_count
+        .checked_add(1)
+        .ok_or(MembersError::MathOverflow)?;
 
     emit!(MerchantAdd {
         merchant: params.merchant,
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::AddressDenylisted;
+use crate::state::{DenylistEntry, MembersStore};
+use crate::{DENYLIST_SEED, MEMBERS_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddToDenylistParams {
+    pub address: Pubkey,
+    pub reason_code: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddToDenylistParams)]
+pub struct AddToDenylist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        constraint = members_store.is_compliance_authority(&compliance_authority.key()) @ MembersError::Unauthorized
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        init,
+        payer = compliance_authority,
+        space = 8 + DenylistEntry::INIT_SPACE,
+        seeds = [DENYLIST_SEED, params.address.as_ref()],
+        bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_to_denylist(ctx: Context<AddToDenylist>, params: AddToDenylistParams) -> Result<()> {
+    require!(params.address != Pubkey::default(), MembersError::InvalidAddress);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let compliance_authority = ctx.accounts.compliance_authority.key();
+
+    let denylist_entry = &mut ctx.accounts.denylist_entry;
+    denylist_entry.address = params.address;
+    denylist_entry.reason_code = params.reason_code;
+    denylist_entry.denylisted_at = timestamp;
+    denylist_entry.denylisted_by = compliance_authority;
+    denylist_entry.bump = ctx.bumps.denylist_entry;
+
+    emit!(AddressDenylisted {
+        address: params.address,
+        reason_code: params.reason_code,
+        timestamp,
+        compliance_authority,
+    });
+    Ok(())
+}
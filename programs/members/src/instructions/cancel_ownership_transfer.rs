@@ -0,0 +1,36 @@
+use crate::errors::MembersError;
+use crate::events::OwnershipTransferCanceled;
+use crate::MembersStore;
+use crate::MEMBERS_SEED;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        has_one = owner @ MembersError::Unauthorized
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    pub owner: Signer<'info>,
+}
+
+pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    let members_store = &mut ctx.accounts.members_store;
+    require!(
+        members_store.pending_owner != Pubkey::default(),
+        MembersError::NoPendingOwner
+    );
+
+    let canceled_owner = members_store.pending_owner;
+    members_store.pending_owner = Pubkey::default();
+    members_store.pending_owner_deadline = 0;
+
+    emit!(OwnershipTransferCanceled {
+        owner: members_store.owner,
+        canceled_owner,
+    });
+    Ok(())
+}
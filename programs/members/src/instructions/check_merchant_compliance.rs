@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::events::MerchantComplianceExpiring;
+use crate::state::{MerchantInfo, COMPLIANCE_WARNING_PERIOD};
+use crate::MERCHANT_INFO_SEED;
+
+// Permissionless, meant to be cranked periodically so dashboards get an
+// on-chain alert before (and after) a merchant's compliance lapses
+#[derive(Accounts)]
+pub struct CheckMerchantCompliance<'info> {
+    #[account(
+        seeds = [MERCHANT_INFO_SEED, merchant_info.merchant.as_ref()],
+        bump = merchant_info.bump
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+}
+
+pub fn check_merchant_compliance(ctx: Context<CheckMerchantCompliance>) -> Result<()> {
+    let merchant_info = &ctx.accounts.merchant_info;
+    let seconds_remaining = merchant_info
+        .compliance_valid_until
+        .saturating_sub(Clock::get()?.unix_timestamp);
+
+    if seconds_remaining <= COMPLIANCE_WARNING_PERIOD {
+        emit!(MerchantComplianceExpiring {
+            merchant: merchant_info.merchant,
+            compliance_valid_until: merchant_info.compliance_valid_until,
+            seconds_remaining,
+        });
+    }
+    Ok(())
+}
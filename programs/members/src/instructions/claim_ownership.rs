@@ -19,11 +19,16 @@ pub struct ClaimOwnership<'info> {
 
 pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
     let members_store = &mut ctx.accounts.members_store;
+    require!(
+        Clock::get()?.unix_timestamp <= members_store.pending_owner_deadline,
+        MembersError::NominationExpired
+    );
     let pending_owner = members_store.pending_owner;
 
     let previous_owner = members_store.owner;
     members_store.owner = pending_owner;
     members_store.pending_owner = Pubkey::default();
+    members_store.pending_owner_deadline = 0;
 
     emit!(OwnershipTransferred {
         previous_owner,
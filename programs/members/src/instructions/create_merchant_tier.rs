@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MerchantTierCreated;
+use crate::state::{MembersStore, MerchantTier};
+use crate::{MEMBERS_SEED, MERCHANT_TIER_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MerchantTierParams {
+    pub name: String,
+    pub max_request_amount: u64,
+    pub daily_cap: u64,
+    pub fee_bps: u16,
+    pub burns_allowed: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MerchantTierParams)]
+pub struct CreateMerchantTier<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MerchantTier::INIT_SPACE,
+        seeds = [MERCHANT_TIER_SEED, params.name.as_bytes()],
+        bump
+    )]
+    pub merchant_tier: Account<'info, MerchantTier>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_merchant_tier(
+    ctx: Context<CreateMerchantTier>,
+    params: MerchantTierParams,
+) -> Result<()> {
+    MerchantTier::validate_name(&params.name)?;
+    MerchantTier::validate_policy(params.max_request_amount, params.daily_cap, params.fee_bps)?;
+
+    let merchant_tier = &mut ctx.accounts.merchant_tier;
+    merchant_tier.name = params.name.clone();
+    merchant_tier.max_request_amount = params.max_request_amount;
+    merchant_tier.daily_cap = params.daily_cap;
+    merchant_tier.fee_bps = params.fee_bps;
+    merchant_tier.burns_allowed = params.burns_allowed;
+    merchant_tier.bump = ctx.bumps.merchant_tier;
+
+    emit!(MerchantTierCreated {
+        tier: merchant_tier.key(),
+        name: params.name,
+        max_request_amount: params.max_request_amount,
+        daily_cap: params.daily_cap,
+        fee_bps: params.fee_bps,
+        burns_allowed: params.burns_allowed,
+    });
+    Ok(())
+}
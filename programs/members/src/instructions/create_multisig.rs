@@ -0,0 +1,54 @@
+use crate::errors::MembersError;
+use crate::events::MultisigCreated;
+use crate::state::{MembersStore, Multisig};
+use crate::{MEMBERS_SEED, MULTISIG_AUTHORITY_SEED, MULTISIG_SEED};
+use anchor_lang::prelude::*;
+
+pub use multisig_core::MultisigSignersParams;
+
+#[derive(Accounts)]
+#[instruction(params: MultisigSignersParams)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Multisig::INIT_SPACE,
+        seeds = [MULTISIG_SEED],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    /// CHECK: PDA that signs executed proposals, nominated as owner afterwards
+    #[account(seeds = [MULTISIG_AUTHORITY_SEED], bump)]
+    pub multisig_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Ownership is handed over with transfer_ownership to the multisig authority,
+// which claims it through a proposal
+pub fn create_multisig(ctx: Context<CreateMultisig>, params: MultisigSignersParams) -> Result<()> {
+    multisig_core::validate_signers(&params.signers, params.threshold)
+        .map_err(MembersError::from)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = params.signers.clone();
+    multisig.threshold = params.threshold;
+    multisig.proposal_count = 0;
+    multisig.generation = 0;
+    multisig.authority_bump = ctx.bumps.multisig_authority;
+    multisig.bump = ctx.bumps.multisig;
+
+    emit!(MultisigCreated {
+        authority: ctx.accounts.multisig_authority.key(),
+        signers: params.signers,
+        threshold: params.threshold,
+    });
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::errors::MembersError;
+use crate::events::MultisigProposalExecuted;
+use crate::state::{Multisig, MultisigProposal};
+use crate::{MULTISIG_AUTHORITY_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ExecuteMultisigProposal<'info> {
+    // Permissionless once the threshold is met
+    pub executor: Signer<'info>,
+    // Writable so the proposal can update it, reloaded before exit
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// CHECK: receives the rent of the proposal account
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    // remaining_accounts: the proposal's accounts, in order
+}
+
+pub fn execute_multisig_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    multisig_core::ensure_executable(
+        &proposal.approvals,
+        proposal.generation,
+        multisig.generation,
+        multisig.threshold,
+    )
+    .map_err(MembersError::from)?;
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let instruction = multisig_core::proposal_instruction(
+        crate::ID,
+        &proposal.accounts,
+        &proposal.data,
+        remaining_accounts,
+    )
+    .map_err(MembersError::from)?;
+    let authority_bump = multisig.authority_bump;
+    invoke_signed(
+        &instruction,
+        remaining_accounts,
+        &[&[MULTISIG_AUTHORITY_SEED, &[authority_bump]]],
+    )?;
+
+    // Pick up changes the proposal made to the signer set
+    ctx.accounts.multisig.reload()?;
+
+    emit!(MultisigProposalExecuted {
+        id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
@@ -23,8 +23,11 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     let members_store = &mut ctx.accounts.members_store;
     members_store.owner = ctx.accounts.payer.key();
     members_store.custodian = Pubkey::default();
+    members_store.factory = Pubkey::default();
+    members_store.compliance_authority = Pubkey::default();
     members_store.merchant_count = 0;
     members_store.pending_owner = Pubkey::default();
+    members_store.pending_owner_deadline = 0;
     members_store.bump = ctx.bumps.members_store;
 
     emit!(MembersInitialized {
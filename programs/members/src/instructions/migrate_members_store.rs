@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::MembersError;
+use crate::events::{ComplianceAuthoritySet, FactorySet, MembersStoreMigrated};
+use crate::state::{MembersStore, MembersStoreV0};
+use crate::MEMBERS_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateMembersStoreParams {
+    pub factory: Pubkey, // merchants resign through it, so it is set as part of the migration
+    pub compliance_authority: Pubkey, // merchants can't mint or burn until it grants them compliance
+    pub pending_owner_validity_period: i64, // seconds a legacy nomination stays claimable, 0 drops it
+}
+
+// Rewrites a members store created with the original layout into the current
+// one, the account is grown in place and the owner tops up its rent
+#[derive(Accounts)]
+#[instruction(params: MigrateMembersStoreParams)]
+pub struct MigrateMembersStore<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: still in the legacy layout, owner and discriminator are checked by the handler
+    #[account(
+        mut,
+        seeds = [MEMBERS_SEED],
+        bump,
+        owner = crate::ID @ MembersError::InvalidProgramId
+    )]
+    pub members_store: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_members_store(
+    ctx: Context<MigrateMembersStore>,
+    params: MigrateMembersStoreParams,
+) -> Result<()> {
+    require!(params.factory != Pubkey::default(), MembersError::InvalidAddress);
+    require!(
+        params.compliance_authority != Pubkey::default(),
+        MembersError::InvalidAddress
+    );
+
+    let members_store_info = ctx.accounts.members_store.to_account_info();
+    require!(
+        members_store_info.data_len() == 8 + MembersStoreV0::INIT_SPACE,
+        MembersError::AlreadyMigrated
+    );
+    let legacy = {
+        let data = members_store_info.try_borrow_data()?;
+        require!(
+            data[..8] == *MembersStore::DISCRIMINATOR,
+            MembersError::AlreadyMigrated
+        );
+        MembersStoreV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.owner, ctx.accounts.owner.key(), MembersError::Unauthorized);
+    require!(
+        params.pending_owner_validity_period >= 0,
+        MembersError::InvalidValidityPeriod
+    );
+
+    // nominations made under the original layout had no deadline, they get one now or are dropped
+    let (pending_owner, pending_owner_deadline) = if legacy.pending_owner != Pubkey::default()
+        && params.pending_owner_validity_period > 0
+    {
+        let deadline = Clock::get()?
+            .unix_timestamp
+            .checked_add(params.pending_owner_validity_period)
+            .ok_or(MembersError::MathOverflow)?;
+        (legacy.pending_owner, deadline)
+    } else {
+        (Pubkey::default(), 0)
+    };
+
+    let members_store = MembersStore {
+        owner: legacy.owner,
+        custodian: legacy.custodian,
+        factory: params.factory,
+        compliance_authority: params.compliance_authority,
+        merchant_count: u64::from(legacy.merchant_count),
+        pending_owner,
+        pending_owner_deadline,
+        bump: legacy.bump,
+    };
+
+    let new_len = 8 + MembersStore::INIT_SPACE;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(members_store_info.lamports());
+    if rent_due > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: members_store_info.clone(),
+            },
+        );
+        transfer(cpi_ctx, rent_due)?;
+    }
+    members_store_info.realloc(new_len, false)?;
+    members_store.try_serialize(&mut &mut members_store_info.try_borrow_mut_data()?[..])?;
+
+    emit!(FactorySet {
+        factory: params.factory,
+    });
+    emit!(ComplianceAuthoritySet {
+        compliance_authority: params.compliance_authority,
+    });
+    emit!(MembersStoreMigrated {
+        owner: members_store.owner,
+        merchant_count: members_store.merchant_count,
+    });
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::MembersError;
+use crate::events::MerchantInfoMigrated;
+use crate::state::{MembersStore, MerchantInfo, MerchantInfoV0};
+use crate::{MEMBERS_SEED, MERCHANT_INFO_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateMerchantInfoParams {
+    pub merchant: Pubkey,
+}
+
+// Rewrites a merchant info created with the original layout into the current
+// one, the account is grown in place and the owner tops up its rent
+#[derive(Accounts)]
+#[instruction(params: MigrateMerchantInfoParams)]
+pub struct MigrateMerchantInfo<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    /// CHECK: still in the legacy layout, owner and discriminator are checked by the handler
+    #[account(
+        mut,
+        seeds = [MERCHANT_INFO_SEED, params.merchant.as_ref()],
+        bump,
+        owner = crate::ID @ MembersError::InvalidProgramId
+    )]
+    pub merchant_info: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_merchant_info(
+    ctx: Context<MigrateMerchantInfo>,
+    params: MigrateMerchantInfoParams,
+) -> Result<()> {
+    let merchant_info_info = ctx.accounts.merchant_info.to_account_info();
+    require!(
+        merchant_info_info.data_len() == 8 + MerchantInfoV0::INIT_SPACE,
+        MembersError::AlreadyMigrated
+    );
+    let legacy = {
+        let data = merchant_info_info.try_borrow_data()?;
+        require!(
+            data[..8] == *MerchantInfo::DISCRIMINATOR,
+            MembersError::AlreadyMigrated
+        );
+        MerchantInfoV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.merchant, params.merchant, MembersError::MerchantNotFound);
+
+    // legacy merchants start without compliance and unrestricted, like a freshly added one
+    let merchant_info = MerchantInfo {
+        merchant: legacy.merchant,
+        compliance_valid_until: 0,
+        tier: Pubkey::default(),
+        bump: legacy.bump,
+    };
+
+    let new_len = 8 + MerchantInfo::INIT_SPACE;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(merchant_info_info.lamports());
+    if rent_due > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: merchant_info_info.clone(),
+            },
+        );
+        transfer(cpi_ctx, rent_due)?;
+    }
+    merchant_info_info.realloc(new_len, false)?;
+    merchant_info.try_serialize(&mut &mut merchant_info_info.try_borrow_mut_data()?[..])?;
+
+    emit!(MerchantInfoMigrated {
+        merchant: merchant_info.merchant,
+    });
+    Ok(())
+}
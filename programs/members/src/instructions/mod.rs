@@ -1,15 +1,55 @@
 // Module declarations
 pub mod add_merchant;
+pub mod add_to_denylist;
+pub mod approve_multisig_proposal;
+pub mod cancel_multisig_proposal;
+pub mod cancel_ownership_transfer;
+pub mod check_merchant_compliance;
 pub mod claim_ownership;
+pub mod create_merchant_tier;
+pub mod create_multisig;
+pub mod create_multisig_proposal;
+pub mod execute_multisig_proposal;
 pub mod initialize;
+pub mod migrate_members_store;
+pub mod migrate_merchant_info;
+pub mod remove_from_denylist;
 pub mod remove_merchant;
+pub mod resign_merchant;
+pub mod set_compliance_authority;
 pub mod set_custodian;
+pub mod set_factory;
+pub mod set_merchant_compliance;
+pub mod set_merchant_tier;
+pub mod set_multisig_signers;
 pub mod transfer_ownership;
+pub mod update_merchant_tier;
+pub mod verify_members;
 
 // Re-export all instructions
 pub use add_merchant::*;
+pub use add_to_denylist::*;
+pub use approve_multisig_proposal::*;
+pub use cancel_multisig_proposal::*;
+pub use cancel_ownership_transfer::*;
+pub use check_merchant_compliance::*;
 pub use claim_ownership::*;
+pub use create_merchant_tier::*;
+pub use create_multisig::*;
+pub use create_multisig_proposal::*;
+pub use execute_multisig_proposal::*;
 pub use initialize::*;
+pub use migrate_members_store::*;
+pub use migrate_merchant_info::*;
+pub use remove_from_denylist::*;
 pub use remove_merchant::*;
+pub use resign_merchant::*;
+pub use set_compliance_authority::*;
 pub use set_custodian::*;
+pub use set_factory::*;
+pub use set_merchant_compliance::*;
+pub use set_merchant_tier::*;
+pub use set_multisig_signers::*;
 pub use transfer_ownership::*;
+pub use update_merchant_tier::*;
+pub use verify_members::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::AddressRemovedFromDenylist;
+use crate::state::{DenylistEntry, MembersStore};
+use crate::{DENYLIST_SEED, MEMBERS_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveFromDenylistParams {
+    pub address: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RemoveFromDenylistParams)]
+pub struct RemoveFromDenylist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        constraint = members_store.is_compliance_authority(&compliance_authority.key()) @ MembersError::Unauthorized
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        mut,
+        seeds = [DENYLIST_SEED, params.address.as_ref()],
+        bump = denylist_entry.bump,
+        close = compliance_authority
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+}
+
+pub fn remove_from_denylist(
+    ctx: Context<RemoveFromDenylist>,
+    params: RemoveFromDenylistParams,
+) -> Result<()> {
+    emit!(AddressRemovedFromDenylist {
+        address: params.address,
+        reason_code: ctx.accounts.denylist_entry.reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+        compliance_authority: ctx.accounts.compliance_authority.key(),
+    });
+    Ok(())
+}
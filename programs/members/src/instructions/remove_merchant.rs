@@ -37,7 +37,10 @@ pub fn remove_merchant(
 ) -> Result<()> {
     // update counter
     let members_store = &mut ctx.accounts.members_store;
-    members_store.merchant_count = members_store.merchant_count.saturating_sub(1);
+    members_store.merchant_count = members_store
+        .merchant_count
+        .checked_sub(1)
+        .ok_or(MembersError::MathOverflow)?;
     // merchant info account will be closed and refunded automatically
     emit!(MerchantRemove {
         merchant: params.merchant,
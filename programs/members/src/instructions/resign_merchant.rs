@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MerchantResigned;
+use crate::state::{MembersStore, MerchantInfo};
+use crate::{FACTORY_SEED, MEMBERS_SEED, MERCHANT_INFO_SEED};
+
+// Invoked by the factory via CPI once it has verified that the merchant has no
+// pending requests and has released its factory-side accounts.
+#[derive(Accounts)]
+pub struct ResignMerchant<'info> {
+    pub merchant: Signer<'info>,
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump,
+        seeds::program = members_store.factory
+    )]
+    pub factory_store: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    /// CHECK: rent receiver, the owner paid for the merchant info account in add_merchant
+    #[account(
+        mut,
+        address = members_store.owner @ MembersError::InvalidOwner
+    )]
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [MERCHANT_INFO_SEED, merchant.key().as_ref()],
+        bump = merchant_info.bump,
+        constraint = merchant_info.merchant == merchant.key() @ MembersError::MerchantNotFound
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+}
+
+pub fn resign_merchant(ctx: Context<ResignMerchant>) -> Result<()> {
+    // update counter
+    let members_store = &mut ctx.accounts.members_store;
+    members_store.merchant_count = members_store
+        .merchant_count
+        .checked_sub(1)
+        .ok_or(MembersError::MathOverflow)?;
+    // merchant info account will be closed and refunded to the owner automatically
+    emit!(MerchantResigned {
+        merchant: ctx.accounts.merchant.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::ComplianceAuthoritySet;
+use crate::state::MembersStore;
+use crate::MEMBERS_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetComplianceAuthorityParams {
+    pub compliance_authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetComplianceAuthorityParams)]
+pub struct SetComplianceAuthority<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+}
+
+pub fn set_compliance_authority(
+    ctx: Context<SetComplianceAuthority>,
+    params: SetComplianceAuthorityParams,
+) -> Result<()> {
+    require!(
+        params.compliance_authority != Pubkey::default(),
+        MembersError::InvalidAddress
+    );
+
+    let members_store = &mut ctx.accounts.members_store;
+    members_store.compliance_authority = params.compliance_authority;
+
+    emit!(ComplianceAuthoritySet {
+        compliance_authority: params.compliance_authority,
+    });
+    Ok(())
+}
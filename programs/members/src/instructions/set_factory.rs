@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::FactorySet;
+use crate::state::MembersStore;
+use crate::MEMBERS_SEED;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFactoryParams {
+    pub factory: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetFactoryParams)]
+pub struct SetFactory<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+}
+
+pub fn set_factory(ctx: Context<SetFactory>, params: SetFactoryParams) -> Result<()> {
+    require!(params.factory != Pubkey::default(), MembersError::InvalidAddress);
+
+    let members_store = &mut ctx.accounts.members_store;
+    members_store.factory = params.factory;
+
+    emit!(FactorySet {
+        factory: params.factory,
+    });
+    Ok(())
+}
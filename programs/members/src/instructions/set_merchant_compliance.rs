@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MerchantComplianceSet;
+use crate::state::{MembersStore, MerchantInfo};
+use crate::{MEMBERS_SEED, MERCHANT_INFO_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMerchantComplianceParams {
+    pub merchant: Pubkey,
+    pub compliance_valid_until: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetMerchantComplianceParams)]
+pub struct SetMerchantCompliance<'info> {
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump,
+        constraint = members_store.is_compliance_authority(&compliance_authority.key()) @ MembersError::Unauthorized
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_INFO_SEED, params.merchant.as_ref()],
+        bump = merchant_info.bump
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+}
+
+pub fn set_merchant_compliance(
+    ctx: Context<SetMerchantCompliance>,
+    params: SetMerchantComplianceParams,
+) -> Result<()> {
+    // an expiry in the past is allowed, it suspends the merchant immediately
+    require!(
+        params.compliance_valid_until >= 0,
+        MembersError::InvalidComplianceExpiry
+    );
+
+    let merchant_info = &mut ctx.accounts.merchant_info;
+    merchant_info.compliance_valid_until = params.compliance_valid_until;
+
+    emit!(MerchantComplianceSet {
+        merchant: params.merchant,
+        compliance_valid_until: params.compliance_valid_until,
+    });
+    Ok(())
+}
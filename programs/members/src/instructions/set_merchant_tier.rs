@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MerchantTierAssigned;
+use crate::state::{MembersStore, MerchantInfo, MerchantTier};
+use crate::{MEMBERS_SEED, MERCHANT_INFO_SEED, MERCHANT_TIER_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMerchantTierParams {
+    pub merchant: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetMerchantTierParams)]
+pub struct SetMerchantTier<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_INFO_SEED, params.merchant.as_ref()],
+        bump = merchant_info.bump
+    )]
+    pub merchant_info: Account<'info, MerchantInfo>,
+    // omit to remove the merchant from its tier
+    #[account(
+        seeds = [MERCHANT_TIER_SEED, merchant_tier.name.as_bytes()],
+        bump = merchant_tier.bump
+    )]
+    pub merchant_tier: Option<Account<'info, MerchantTier>>,
+}
+
+pub fn set_merchant_tier(
+    ctx: Context<SetMerchantTier>,
+    params: SetMerchantTierParams,
+) -> Result<()> {
+    let tier = ctx
+        .accounts
+        .merchant_tier
+        .as_ref()
+        .map_or(Pubkey::default(), |merchant_tier| merchant_tier.key());
+
+    let merchant_info = &mut ctx.accounts.merchant_info;
+    merchant_info.tier = tier;
+
+    emit!(MerchantTierAssigned {
+        merchant: params.merchant,
+        tier,
+    });
+    Ok(())
+}
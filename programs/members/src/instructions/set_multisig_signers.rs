@@ -0,0 +1,41 @@
+use crate::errors::MembersError;
+use crate::events::MultisigSignersSet;
+use crate::instructions::MultisigSignersParams;
+use crate::state::Multisig;
+use crate::{MULTISIG_AUTHORITY_SEED, MULTISIG_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(params: MultisigSignersParams)]
+pub struct SetMultisigSigners<'info> {
+    // Only reachable through an executed proposal
+    #[account(seeds = [MULTISIG_AUTHORITY_SEED], bump = multisig.authority_bump)]
+    pub multisig_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+}
+
+pub fn set_multisig_signers(
+    ctx: Context<SetMultisigSigners>,
+    params: MultisigSignersParams,
+) -> Result<()> {
+    multisig_core::validate_signers(&params.signers, params.threshold)
+        .map_err(MembersError::from)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = params.signers.clone();
+    multisig.threshold = params.threshold;
+    multisig.generation =
+        multisig_core::next_generation(multisig.generation).map_err(MembersError::from)?;
+
+    emit!(MultisigSignersSet {
+        signers: params.signers,
+        threshold: params.threshold,
+        generation: multisig.generation,
+    });
+    Ok(())
+}
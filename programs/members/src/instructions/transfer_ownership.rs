@@ -7,6 +7,7 @@ use anchor_lang::prelude::*;
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TransferOwnershipParams {
     pub new_owner: Pubkey,
+    pub validity_period: i64, // seconds the nomination can be claimed for
 }
 
 #[derive(Accounts)]
@@ -31,11 +32,19 @@ pub fn transfer_ownership(
         params.new_owner != Pubkey::default(),
         MembersError::InvalidOwner
     );
+    require!(params.validity_period > 0, MembersError::InvalidValidityPeriod);
+    let deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(params.validity_period)
+        .ok_or(MembersError::MathOverflow)?;
+
     members_store.pending_owner = params.new_owner;
+    members_store.pending_owner_deadline = deadline;
 
     emit!(OwnershipTransferStarted {
         previous_owner: members_store.owner,
         new_owner: params.new_owner,
+        deadline,
     });
     Ok(())
 }
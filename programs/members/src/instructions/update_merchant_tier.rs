@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MerchantTierUpdated;
+use crate::instructions::MerchantTierParams;
+use crate::state::{MembersStore, MerchantTier};
+use crate::{MEMBERS_SEED, MERCHANT_TIER_SEED};
+
+#[derive(Accounts)]
+#[instruction(params: MerchantTierParams)]
+pub struct UpdateMerchantTier<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ MembersError::Unauthorized,
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+    #[account(
+        mut,
+        seeds = [MERCHANT_TIER_SEED, params.name.as_bytes()],
+        bump = merchant_tier.bump
+    )]
+    pub merchant_tier: Account<'info, MerchantTier>,
+}
+
+// Merchants reference the tier account, so the new policy applies to every
+// merchant assigned to it from the next request on
+pub fn update_merchant_tier(
+    ctx: Context<UpdateMerchantTier>,
+    params: MerchantTierParams,
+) -> Result<()> {
+    MerchantTier::validate_policy(params.max_request_amount, params.daily_cap, params.fee_bps)?;
+
+    let merchant_tier = &mut ctx.accounts.merchant_tier;
+    merchant_tier.max_request_amount = params.max_request_amount;
+    merchant_tier.daily_cap = params.daily_cap;
+    merchant_tier.fee_bps = params.fee_bps;
+    merchant_tier.burns_allowed = params.burns_allowed;
+
+    emit!(MerchantTierUpdated {
+        tier: merchant_tier.key(),
+        name: params.name,
+        max_request_amount: params.max_request_amount,
+        daily_cap: params.daily_cap,
+        fee_bps: params.fee_bps,
+        burns_allowed: params.burns_allowed,
+    });
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+use crate::events::MembersVerified;
+use crate::state::{MembersStore, MerchantInfo};
+use crate::{MEMBERS_SEED, MERCHANT_INFO_SEED};
+
+// Permissionless registry audit: every MerchantInfo account is passed as a
+// remaining account and the number of distinct valid ones must match
+// merchant_count
+#[derive(Accounts)]
+pub struct VerifyMembers<'info> {
+    #[account(
+        seeds = [MEMBERS_SEED],
+        bump = members_store.bump
+    )]
+    pub members_store: Account<'info, MembersStore>,
+}
+
+pub fn verify_members(ctx: Context<VerifyMembers>) -> Result<()> {
+    let mut merchant_infos = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for account in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*account.owner, crate::ID, MembersError::InvalidProgramId);
+        let merchant_info = MerchantInfo::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        // make sure the account is the canonical PDA of the merchant it claims
+        let expected = Pubkey::create_program_address(
+            &[
+                MERCHANT_INFO_SEED,
+                merchant_info.merchant.as_ref(),
+                &[merchant_info.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| MembersError::MerchantNotFound)?;
+        require_keys_eq!(expected, account.key(), MembersError::MerchantNotFound);
+
+        merchant_infos.push(account.key());
+    }
+
+    merchant_infos.sort_unstable();
+    merchant_infos.dedup();
+    require!(
+        merchant_infos.len() == ctx.remaining_accounts.len(),
+        MembersError::DuplicateMerchantInfo
+    );
+
+    let merchant_count = ctx.accounts.members_store.merchant_count;
+    let verified_count = merchant_infos.len() as u64;
+    let consistent = merchant_count == verified_count;
+    msg!(
+        "merchant_count: {}, verified merchant info accounts: {}, consistent: {}",
+        merchant_count,
+        verified_count,
+        consistent
+    );
+
+    emit!(MembersVerified {
+        merchant_count,
+        verified_count,
+        consistent,
+    });
+    require!(consistent, MembersError::MerchantCountMismatch);
+    Ok(())
+}
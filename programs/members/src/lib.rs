@@ -17,6 +17,10 @@ declare_id!("9fMhjBNMKR6AhuELQiUpdov1B2Ec6ppNLGnBhiWFyUiS");
 // Seeds constants
 pub const MEMBERS_SEED: &[u8] = b"members";
 pub const MERCHANT_INFO_SEED: &[u8] = b"merchant_info";
+pub const FACTORY_SEED: &[u8] = b"factory";
+pub const MERCHANT_TIER_SEED: &[u8] = b"merchant_tier";
+pub const DENYLIST_SEED: &[u8] = b"denylist";
+pub use multisig_core::{MULTISIG_AUTHORITY_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED};
 
 #[program]
 pub mod members {
@@ -26,10 +30,46 @@ pub mod members {
         instructions::initialize::initialize(ctx)
     }
 
+    pub fn migrate_members_store(
+        ctx: Context<MigrateMembersStore>,
+        params: MigrateMembersStoreParams,
+    ) -> Result<()> {
+        instructions::migrate_members_store::migrate_members_store(ctx, params)
+    }
+
+    pub fn migrate_merchant_info(
+        ctx: Context<MigrateMerchantInfo>,
+        params: MigrateMerchantInfoParams,
+    ) -> Result<()> {
+        instructions::migrate_merchant_info::migrate_merchant_info(ctx, params)
+    }
+
     pub fn set_custodian(ctx: Context<SetCustodian>, params: SetCustodianParams) -> Result<()> {
         instructions::set_custodian::set_custodian(ctx, params)
     }
 
+    pub fn set_factory(ctx: Context<SetFactory>, params: SetFactoryParams) -> Result<()> {
+        instructions::set_factory::set_factory(ctx, params)
+    }
+
+    pub fn set_compliance_authority(
+        ctx: Context<SetComplianceAuthority>,
+        params: SetComplianceAuthorityParams,
+    ) -> Result<()> {
+        instructions::set_compliance_authority::set_compliance_authority(ctx, params)
+    }
+
+    pub fn set_merchant_compliance(
+        ctx: Context<SetMerchantCompliance>,
+        params: SetMerchantComplianceParams,
+    ) -> Result<()> {
+        instructions::set_merchant_compliance::set_merchant_compliance(ctx, params)
+    }
+
+    pub fn check_merchant_compliance(ctx: Context<CheckMerchantCompliance>) -> Result<()> {
+        instructions::check_merchant_compliance::check_merchant_compliance(ctx)
+    }
+
     pub fn add_merchant(ctx: Context<AddMerchant>, params: AddMerchantParams) -> Result<()> {
         instructions::add_merchant::add_merchant(ctx, params)
     }
@@ -41,6 +81,46 @@ pub mod members {
         instructions::remove_merchant::remove_merchant(ctx, params)
     }
 
+    pub fn resign_merchant(ctx: Context<ResignMerchant>) -> Result<()> {
+        instructions::resign_merchant::resign_merchant(ctx)
+    }
+
+    pub fn add_to_denylist(ctx: Context<AddToDenylist>, params: AddToDenylistParams) -> Result<()> {
+        instructions::add_to_denylist::add_to_denylist(ctx, params)
+    }
+
+    pub fn remove_from_denylist(
+        ctx: Context<RemoveFromDenylist>,
+        params: RemoveFromDenylistParams,
+    ) -> Result<()> {
+        instructions::remove_from_denylist::remove_from_denylist(ctx, params)
+    }
+
+    pub fn create_merchant_tier(
+        ctx: Context<CreateMerchantTier>,
+        params: MerchantTierParams,
+    ) -> Result<()> {
+        instructions::create_merchant_tier::create_merchant_tier(ctx, params)
+    }
+
+    pub fn update_merchant_tier(
+        ctx: Context<UpdateMerchantTier>,
+        params: MerchantTierParams,
+    ) -> Result<()> {
+        instructions::update_merchant_tier::update_merchant_tier(ctx, params)
+    }
+
+    pub fn set_merchant_tier(
+        ctx: Context<SetMerchantTier>,
+        params: SetMerchantTierParams,
+    ) -> Result<()> {
+        instructions::set_merchant_tier::set_merchant_tier(ctx, params)
+    }
+
+    pub fn verify_members(ctx: Context<VerifyMembers>) -> Result<()> {
+        instructions::verify_members::verify_members(ctx)
+    }
+
     pub fn transfer_ownership(
         ctx: Context<TransferOwnership>,
         params: TransferOwnershipParams,
@@ -51,4 +131,40 @@ pub mod members {
     pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
         instructions::claim_ownership::claim_ownership(ctx)
     }
+
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer::cancel_ownership_transfer(ctx)
+    }
+
+    pub fn create_multisig(ctx: Context<CreateMultisig>, params: MultisigSignersParams) -> Result<()> {
+        instructions::create_multisig::create_multisig(ctx, params)
+    }
+
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        params: MultisigSignersParams,
+    ) -> Result<()> {
+        instructions::set_multisig_signers::set_multisig_signers(ctx, params)
+    }
+
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        params: CreateMultisigProposalParams,
+    ) -> Result<()> {
+        instructions::create_multisig_proposal::create_multisig_proposal(ctx, params)
+    }
+
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        instructions::approve_multisig_proposal::approve_multisig_proposal(ctx)
+    }
+
+    pub fn execute_multisig_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_multisig_proposal::execute_multisig_proposal(ctx)
+    }
+
+    pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+        instructions::cancel_multisig_proposal::cancel_multisig_proposal(ctx)
+    }
 }
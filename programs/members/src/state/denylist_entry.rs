@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+// Existence of the PDA is what blocks the address, checked by the factory and the transfer hook
+#[derive(InitSpace, Debug)]
+#[account]
+pub struct DenylistEntry {
+    pub address: Pubkey,               // denylisted wallet address
+    pub reason_code: u16,              // compliance reason code, defined off-chain (sanctions list, court order, ...)
+    pub denylisted_at: i64,            // unix timestamp of the listing
+    pub denylisted_by: Pubkey,         // compliance authority that added the entry
+    pub bump: u8,                      // PDA bump value for future verification
+}
+
+impl DenylistEntry {
+    // The PDA can only hold data once the members program created it
+    pub fn is_denylisted(entry: &AccountInfo) -> bool {
+        entry.owner == &crate::ID && !entry.data_is_empty()
+    }
+}
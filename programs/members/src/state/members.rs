@@ -5,8 +5,11 @@ use anchor_lang::prelude::*;
 pub struct MembersStore {
     pub owner: Pubkey,                 // owner's public key
     pub custodian: Pubkey,             // custodian's public key
-    pub merchant_count: u16,           // merchant count (kept for statistics)
+    pub factory: Pubkey,               // factory program id, allowed to act on behalf of merchants
+    pub compliance_authority: Pubkey,  // compliance authority's public key, re-verifies merchants
+    pub merchant_count: u64,           // merchant count, audited by verify_members
     pub pending_owner: Pubkey,         // pending owner's public key for ownership transfer
+    pub pending_owner_deadline: i64,   // unix timestamp after which the pending owner can no longer claim
     pub bump: u8,                      // PDA bump value
 }
 
@@ -15,4 +18,20 @@ impl MembersStore {
     pub fn is_custodian(&self, address: &Pubkey) -> bool {
         &self.custodian == address
     }
+
+    // Check if address is compliance authority
+    pub fn is_compliance_authority(&self, address: &Pubkey) -> bool {
+        &self.compliance_authority == address
+    }
+}
+
+// Layout deployed before the factory, compliance authority and nomination
+// deadline were added and merchant_count was widened, read by migrate_members_store
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug)]
+pub struct MembersStoreV0 {
+    pub owner: Pubkey,
+    pub custodian: Pubkey,
+    pub merchant_count: u16,
+    pub pending_owner: Pubkey,
+    pub bump: u8,
 }
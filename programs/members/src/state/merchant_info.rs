@@ -1,8 +1,33 @@
 use anchor_lang::prelude::*;
 
+// Window ahead of expiry in which check_merchant_compliance raises an alert
+pub const COMPLIANCE_WARNING_PERIOD: i64 = 30 * 24 * 60 * 60;
+
 #[derive(InitSpace, Debug)]
 #[account]
 pub struct MerchantInfo {
-    pub merchant: Pubkey,      // merchant public key
-    pub bump: u8,              // PDA bump value for future verification
+    pub merchant: Pubkey,              // merchant public key
+    pub compliance_valid_until: i64,   // unix timestamp until which the merchant's KYC is valid, 0 until first set
+    pub tier: Pubkey,                  // assigned merchant tier PDA, default when unrestricted
+    pub bump: u8,                      // PDA bump value for future verification
+}
+
+impl MerchantInfo {
+    // Check if the merchant's compliance is still valid at the given time
+    pub fn is_compliant(&self, now: i64) -> bool {
+        now <= self.compliance_valid_until
+    }
+
+    // Check if the merchant is assigned to a tier
+    pub fn has_tier(&self) -> bool {
+        self.tier != Pubkey::default()
+    }
+}
+
+// Layout deployed before compliance expiry and tiers were added, read by
+// migrate_merchant_info
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug)]
+pub struct MerchantInfoV0 {
+    pub merchant: Pubkey,
+    pub bump: u8,
 }
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::errors::MembersError;
+
+pub const MAX_TIER_NAME_LEN: usize = 32;
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+// Named policy shared by every merchant assigned to it, e.g. "pilot",
+// "standard" or "institutional"
+#[derive(InitSpace, Debug)]
+#[account]
+pub struct MerchantTier {
+    #[max_len(32)]
+    pub name: String,                  // tier name, also the PDA seed
+    pub max_request_amount: u64,       // largest single mint or burn request
+    pub daily_cap: u64,                // total requested amount per merchant per day
+    pub fee_bps: u16,                  // fee rate in basis points
+    pub burns_allowed: bool,           // whether merchants in this tier can burn
+    pub bump: u8,                      // PDA bump value
+}
+
+impl MerchantTier {
+    pub fn validate_name(name: &str) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_TIER_NAME_LEN,
+            MembersError::InvalidTierName
+        );
+        Ok(())
+    }
+
+    pub fn validate_policy(max_request_amount: u64, daily_cap: u64, fee_bps: u16) -> Result<()> {
+        require!(
+            max_request_amount > 0 && max_request_amount <= daily_cap && fee_bps <= MAX_FEE_BPS,
+            MembersError::InvalidTierPolicy
+        );
+        Ok(())
+    }
+}
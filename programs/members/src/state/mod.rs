@@ -1,5 +1,11 @@
+pub mod denylist_entry;
 pub mod members;
 pub mod merchant_info;
+pub mod merchant_tier;
+pub mod multisig;
 
+pub use denylist_entry::*;
 pub use members::*;
 pub use merchant_info::*;
+pub use merchant_tier::*;
+pub use multisig::*;
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("Hook called outside of a transfer")]
+    NotTransferring,
+    #[msg("Destination owner is denylisted")]
+    DestinationDenylisted,
+}
@@ -0,0 +1,52 @@
+use crate::errors::TransferHookError;
+use crate::EXTRA_ACCOUNT_METAS_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplTokenAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use members::program::Members;
+use members::{DenylistEntry, DENYLIST_SEED};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct Execute<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: source owner or delegate, already authorized by Token-2022
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: validated by seeds
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub members_program: Program<'info, Members>,
+    /// CHECK: members denylist PDA of the destination owner, may not exist
+    #[account(
+        seeds = [DENYLIST_SEED, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = members_program.key()
+    )]
+    pub destination_denylist_entry: UncheckedAccount<'info>,
+}
+
+pub fn execute(ctx: Context<Execute>, _amount: u64) -> Result<()> {
+    // Only Token-2022 sets the transferring flag, rejects direct invocations
+    let source_info = ctx.accounts.source_token.to_account_info();
+    let source_data = source_info.try_borrow_data()?;
+    let source_account = StateWithExtensions::<SplTokenAccount>::unpack(&source_data)?;
+    let transfer_hook_account = source_account.get_extension::<TransferHookAccount>()?;
+    require!(
+        bool::from(transfer_hook_account.transferring),
+        TransferHookError::NotTransferring
+    );
+
+    // The denylist applies to every destination, merchants included
+    require!(
+        !DenylistEntry::is_denylisted(&ctx.accounts.destination_denylist_entry),
+        TransferHookError::DestinationDenylisted
+    );
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::EXTRA_ACCOUNT_METAS_SEED;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use members::DENYLIST_SEED;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+// Account indices in the Execute instruction
+const DESTINATION_TOKEN_INDEX: u8 = 2;
+const MEMBERS_PROGRAM_INDEX: u8 = 5;
+// The token account owner is stored at bytes 32..64
+const TOKEN_OWNER_OFFSET: u8 = 32;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: initialized as a TLV account list below
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+fn owner_pda(seed: &[u8], token_account_index: u8) -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_external_pda_with_seeds(
+        MEMBERS_PROGRAM_INDEX,
+        &[
+            Seed::Literal {
+                bytes: seed.to_vec(),
+            },
+            Seed::AccountData {
+                account_index: token_account_index,
+                data_index: TOKEN_OWNER_OFFSET,
+                length: 32,
+            },
+        ],
+        false,
+        false,
+    )?)
+}
+
+// Members program and the destination denylist entry
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&members::ID, false, false)?,
+        owner_pda(DENYLIST_SEED, DESTINATION_TOKEN_INDEX)?,
+    ])
+}
+
+pub fn initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaList>,
+) -> Result<()> {
+    let extra_account_meta_list = ctx.accounts.extra_account_meta_list.to_account_info();
+    let mut data = extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas()?)?;
+    Ok(())
+}
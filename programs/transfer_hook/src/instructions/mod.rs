@@ -0,0 +1,7 @@
+// Module declarations
+pub mod execute;
+pub mod initialize_extra_account_meta_list;
+
+// Re-export all instructions
+pub use execute::*;
+pub use initialize_extra_account_meta_list::*;
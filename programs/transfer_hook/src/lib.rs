@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::{
+    ExecuteInstruction, InitializeExtraAccountMetaListInstruction,
+};
+
+// Submodules
+pub mod errors;
+pub mod instructions;
+
+// Re-exports
+pub use errors::*;
+pub use instructions::*;
+
+declare_id!("HJ2mBiTLs1MRkjoCW3UHL6wx4ZcoRR8e9o73yMpcUVh8");
+
+// Seeds constants, fixed by the transfer hook interface
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+#[program]
+pub mod transfer_hook {
+    use super::*;
+
+    /// Creates the account list Token-2022 resolves for every transfer of the mint
+    #[instruction(discriminator = InitializeExtraAccountMetaListInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        instructions::initialize_extra_account_meta_list::initialize_extra_account_meta_list(ctx)
+    }
+
+    /// Called by Token-2022 on transfer, blocks transfers to denylisted owners
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn execute(ctx: Context<Execute>, amount: u64) -> Result<()> {
+        instructions::execute::execute(ctx, amount)
+    }
+}
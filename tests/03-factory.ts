@@ -9,6 +9,7 @@ import {
 import {
   TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { assert } from "chai";
@@ -23,8 +24,12 @@ import {
   initializeController,
   initializeFactory,
   configureController,
+  configureMembers,
   setCustodian,
   addMerchant,
+  getDenylistEntry,
+  getCustodianBtcAddressBook,
+  getCustodianBtcAddress,
   sharedState,
 } from "../helpers/test-setup";
 
@@ -56,8 +61,8 @@ describe("factory", () => {
   let membersStoreBump: number;
   let merchantInfo: PublicKey;
   let merchantInfoBump: number;
+  let custodianBtcAddressBook: PublicKey;
   let custodianBtcAddress: PublicKey;
-  let custodianBtcAddressBump: number;
   let merchantBtcAddress: PublicKey;
   let merchantBtcAddressBump: number;
   let requestAccount: PublicKey;
@@ -68,6 +73,7 @@ describe("factory", () => {
   const btcAddress = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh";
   const btcTxid =
     "50325250d21ddb5ef821862ea6a0ee0f9229331bf16402d7028f7858c93ecc2c";
+  const btcNetworkFee = new BN(2000); // BTC miner fee paid on burn payouts
   const amount = new BN(100000000); // 1 BTC (100,000,000 satoshis)
   let mintRequestNonce: number = 0;
   let burnRequestNonce: number = 0;
@@ -127,6 +133,9 @@ describe("factory", () => {
       wallet
     );
 
+    // Allow the Factory to act on the Members registry
+    await configureMembers(membersProgram, factoryProgram.programId, wallet);
+
     // Set custodian
     await setCustodian(membersProgram, custodian.publicKey, wallet);
     console.log("Custodian set:", custodian.publicKey.toString());
@@ -153,12 +162,16 @@ describe("factory", () => {
       await new Promise((resolve) => setTimeout(resolve, 500)); // wait for confirmation
     }
 
-    // Derive custodian BTC address account
-    [custodianBtcAddress, custodianBtcAddressBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("custodian_btc_address"), merchant.publicKey.toBuffer()],
-        factoryProgram.programId
-      );
+    // Derive custodian BTC address accounts, the first address gets index 0
+    custodianBtcAddressBook = getCustodianBtcAddressBook(
+      factoryProgram,
+      merchant.publicKey
+    );
+    custodianBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      merchant.publicKey,
+      0
+    );
     console.log("Custodian BTC address PDA:", custodianBtcAddress.toString());
 
     // Ensure custodian is the custodian set in members_store
@@ -186,6 +199,7 @@ describe("factory", () => {
         controllerStore: controllerStore,
         membersStore: membersStore,
         merchantInfo: merchantInfo,
+        custodianBtcAddressBook,
         custodianBtcAddress: custodianBtcAddress,
         systemProgram: SystemProgram.programId,
       })
@@ -203,6 +217,9 @@ describe("factory", () => {
       addressAccount.merchant.toString(),
       merchant.publicKey.toString()
     );
+    assert.equal(addressAccount.index, 0);
+    assert.isTrue(addressAccount.active);
+    assert.equal(addressAccount.retiredAt.toNumber(), 0);
   });
 
   it("Set merchant BTC deposit address", async () => {
@@ -240,6 +257,9 @@ describe("factory", () => {
       addressAccount.merchant.toString(),
       merchant.publicKey.toString()
     );
+    assert.equal(addressAccount.index, 0);
+    assert.isTrue(addressAccount.active);
+    assert.equal(addressAccount.retiredAt.toNumber(), 0);
   });
 
   it("Add mint request by merchant", async () => {
@@ -268,6 +288,7 @@ describe("factory", () => {
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
         requestAccount,
         controllerStore,
@@ -312,6 +333,18 @@ describe("factory", () => {
       requestAccount
     );
 
+    const controllerBefore =
+      await controllerProgram.account.controllerStore.fetch(controllerStore);
+
+    // Capture the controller's Minted event
+    let mintedEvent: any = null;
+    const mintedListener = controllerProgram.addEventListener(
+      "minted",
+      (event) => {
+        mintedEvent = event;
+      }
+    );
+
     await factoryProgram.methods
       .confirmMintRequest({
         nonce: new BN(mintRequestNonce),
@@ -325,6 +358,7 @@ describe("factory", () => {
         requestAccount,
         tokenMint,
         toAddress: merchant.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         tokenAccount: merchantTokenAccount,
         controllerProgram: controllerProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -348,6 +382,36 @@ describe("factory", () => {
     );
     console.log("Merchant token balance:", tokenBalance.value.amount);
     assert.equal(tokenBalance.value.amount, amount.toString());
+
+    // Verify controller supply accounting
+    const controllerAfter =
+      await controllerProgram.account.controllerStore.fetch(controllerStore);
+    assert.equal(
+      controllerAfter.totalMinted.sub(controllerBefore.totalMinted).toString(),
+      amount.toString()
+    );
+    assert.equal(
+      controllerAfter.mintSequence.sub(controllerBefore.mintSequence).toNumber(),
+      1
+    );
+
+    // Verify the controller reported the mint
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await controllerProgram.removeEventListener(mintedListener);
+    assert.isNotNull(mintedEvent);
+    assert.equal(
+      mintedEvent.recipient.toString(),
+      merchant.publicKey.toString()
+    );
+    assert.equal(mintedEvent.amount.toString(), amount.toString());
+    assert.equal(
+      mintedEvent.factory.toString(),
+      factoryProgram.programId.toString()
+    );
+    assert.equal(
+      mintedEvent.mintSequence.toString(),
+      controllerAfter.mintSequence.toString()
+    );
   });
 
   it("Burn tokens by merchant", async () => {
@@ -376,12 +440,16 @@ describe("factory", () => {
     );
     console.log("Controller token account:", controllerTokenAccount.toString());
 
+    const controllerBefore =
+      await controllerProgram.account.controllerStore.fetch(controllerStore);
+
     await factoryProgram.methods
       .burn({
         amount: amount,
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
         requestAccount,
         controllerStore,
@@ -415,6 +483,14 @@ describe("factory", () => {
       tokenBalance.value.amount
     );
     assert.equal(tokenBalance.value.amount, "0");
+
+    // Verify controller supply accounting
+    const controllerAfter =
+      await controllerProgram.account.controllerStore.fetch(controllerStore);
+    assert.equal(
+      controllerAfter.totalBurned.sub(controllerBefore.totalBurned).toString(),
+      amount.toString()
+    );
   });
 
   it("Confirm burn request by custodian", async () => {
@@ -427,6 +503,8 @@ describe("factory", () => {
       .confirmBurnRequest({
         nonce: new BN(burnRequestNonce),
         btcTxid: btcTxid,
+        btcAmountSent: amount.sub(btcNetworkFee),
+        btcNetworkFee: btcNetworkFee,
         requestHash: burnRequestData.hash,
       })
       .accounts({
@@ -446,25 +524,104 @@ describe("factory", () => {
     );
     assert.equal(requestData.status.approved !== undefined, true);
     assert.equal(requestData.btcTxid, btcTxid);
+    assert.equal(requestData.btcAmountSent.toString(), amount.sub(btcNetworkFee).toString());
+    assert.equal(requestData.btcNetworkFee.toString(), btcNetworkFee.toString());
   });
 
-  it("Cancel mint request by merchant", async () => {
-    // First create a new mint request
-    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+  it("Charge protocol fees on mint and burn", async () => {
+    // Treasury token account receiving the fees
+    const treasury = Keypair.generate();
+    const feeTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        walletKP,
+        tokenMint,
+        treasury.publicKey
+      )
+    ).address;
+
+    // 1% on mint, 0.5% on burn, at least 1000 base units
+    await factoryProgram.methods
+      .setFeeConfig({
+        mintFeeBps: 100,
+        burnFeeBps: 50,
+        minFee: new BN(1000),
+      })
+      .accounts({
+        owner: payer,
+        factoryStore,
+        controllerStore,
+        feeRecipient: feeTokenAccount,
+      })
+      .rpc();
+
+    let factoryStoreData = await factoryProgram.account.factoryStore.fetch(
       factoryStore
     );
-    const newMintRequestNonce = factoryStoreData.mintRequestCounter.toNumber();
-    console.log("New mint request nonce:", newMintRequestNonce);
+    assert.equal(factoryStoreData.feeRecipient.toString(), feeTokenAccount.toString());
 
-    [requestAccount, requestAccountBump] = await PublicKey.findProgramAddress(
+    // Mint: the merchant receives the amount net of the fee
+    const mintNonce = factoryStoreData.mintRequestCounter.toNumber();
+    const [mintRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), new BN(mintNonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+
+    // Requests the minimum fee would swallow are rejected when they are opened
+    try {
+      await factoryProgram.methods
+        .addMintRequest({
+          amount: new BN(500),
+          btcTxid: btcTxid,
+          btcDepositAddress: btcAddress,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: mintRequest,
+          controllerStore,
+          merchantInfo,
+          custodianBtcAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      assert.fail("Mint request below the minimum fee should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "FeeExceedsAmount");
+    }
+    const [smallBurnRequest] = PublicKey.findProgramAddressSync(
       [
-        Buffer.from("mint_request"),
-        new BN(newMintRequestNonce).toArrayLike(Buffer, "le", 8),
+        Buffer.from("burn_request"),
+        factoryStoreData.burnRequestCounter.toArrayLike(Buffer, "le", 8),
       ],
       factoryProgram.programId
     );
-    console.log("New mint request account:", requestAccount.toString());
-
+    try {
+      await factoryProgram.methods
+        .burn({ amount: new BN(500) })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: smallBurnRequest,
+          controllerStore,
+          merchantInfo,
+          merchantBtcAddress,
+          tokenMint,
+          tokenAccount: merchantTokenAccount,
+          feeTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      assert.fail("Burn below the minimum fee should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "FeeExceedsAmount");
+    }
     await factoryProgram.methods
       .addMintRequest({
         amount: amount,
@@ -473,8 +630,9 @@ describe("factory", () => {
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
-        requestAccount,
+        requestAccount: mintRequest,
         controllerStore,
         merchantInfo,
         custodianBtcAddress,
@@ -482,54 +640,339 @@ describe("factory", () => {
       })
       .signers([merchant])
       .rpc();
-    console.log("New mint request added");
 
-    // Now cancel this request
-    let cancelRequestData = await factoryProgram.account.requestAccount.fetch(
-      requestAccount
+    const balanceBefore = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
     );
+    const mintRequestData = await factoryProgram.account.requestAccount.fetch(
+      mintRequest
+    );
+    const controllerBefore =
+      await controllerProgram.account.controllerStore.fetch(controllerStore);
 
+    // The fee is minted to the treasury separately, the event reports both mints
+    let confirmedEvent: any = null;
+    const confirmedListener = factoryProgram.addEventListener(
+      "mintConfirmed",
+      (event) => {
+        confirmedEvent = event;
+      }
+    );
     await factoryProgram.methods
-      .cancelMintRequest({
-        nonce: new BN(newMintRequestNonce),
-        requestHash: cancelRequestData.hash,
+      .confirmMintRequest({
+        nonce: new BN(mintNonce),
+        requestHash: mintRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        requestAccount: mintRequest,
+        tokenMint,
+        toAddress: merchant.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        tokenAccount: merchantTokenAccount,
+        feeTokenAccount,
+        controllerProgram: controllerProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
+      .signers([custodian])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await factoryProgram.removeEventListener(confirmedListener);
+    assert.isNotNull(confirmedEvent);
+    const sequenceBefore = controllerBefore.mintSequence;
+    assert.equal(confirmedEvent.mintSequence.toString(), sequenceBefore.addn(1).toString());
+    assert.equal(confirmedEvent.feeMintSequence.toString(), sequenceBefore.addn(2).toString());
+
+    const mintFee = amount.muln(100).divn(10000);
+    assert.equal(confirmedEvent.fee.toString(), mintFee.toString());
+    let merchantBalance = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    assert.equal(merchantBalance.sub(balanceBefore).toString(), amount.sub(mintFee).toString());
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(feeTokenAccount)).value.amount,
+      mintFee.toString()
+    );
+
+    // Burn: the fee is charged on top of the burned amount
+    const burnAmount = new BN(10000);
+    const burnNonce = factoryStoreData.burnRequestCounter.toNumber();
+    const [burnRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_request"), new BN(burnNonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    await factoryProgram.methods
+      .burn({ amount: burnAmount })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
-        requestAccount,
+        requestAccount: burnRequest,
+        controllerStore,
+        merchantInfo,
+        merchantBtcAddress,
+        tokenMint,
+        tokenAccount: merchantTokenAccount,
+        feeTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([merchant])
       .rpc();
-    console.log("Mint request canceled");
 
-    // Verify request status updated to canceled
-    const requestData = await factoryProgram.account.requestAccount.fetch(
-      requestAccount
+    // 0.5% of 10000 is below the flat minimum
+    const burnFee = new BN(1000);
+    const balanceAfterBurn = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    assert.equal(
+      merchantBalance.sub(balanceAfterBurn).toString(),
+      burnAmount.add(burnFee).toString()
+    );
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(feeTokenAccount)).value.amount,
+      mintFee.add(burnFee).toString()
     );
-    assert.equal(requestData.status.canceled !== undefined, true);
-  });
 
-  it("Reject mint request by custodian", async () => {
-    // First create a new mint request
-    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
-      factoryStore
+    // Settle the burn so the merchant has no pending requests left
+    const burnRequestData = await factoryProgram.account.requestAccount.fetch(
+      burnRequest
     );
-    const newMintRequestNonce = factoryStoreData.mintRequestCounter.toNumber();
-    console.log("New mint request nonce for rejection:", newMintRequestNonce);
+    await factoryProgram.methods
+      .confirmBurnRequest({
+        nonce: new BN(burnNonce),
+        btcTxid: btcTxid,
+        btcAmountSent: burnAmount.sub(btcNetworkFee),
+        btcNetworkFee: btcNetworkFee,
+        requestHash: burnRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        requestAccount: burnRequest,
+        controllerStore,
+        membersStore,
+      })
+      .signers([custodian])
+      .rpc();
 
-    [requestAccount, requestAccountBump] = await PublicKey.findProgramAddress(
-      [
-        Buffer.from("mint_request"),
-        new BN(newMintRequestNonce).toArrayLike(Buffer, "le", 8),
-      ],
+    // A merchant tier's fee rate replaces the global one, fixed when the request is opened
+    const [merchantTier] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_tier"), Buffer.from("discounted")],
+      membersProgram.programId
+    );
+    await membersProgram.methods
+      .createMerchantTier({
+        name: "discounted",
+        maxRequestAmount: amount,
+        dailyCap: amount.muln(10),
+        feeBps: 20,
+        burnsAllowed: true,
+      })
+      .accounts({
+        owner: payer,
+        membersStore,
+        merchantTier,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await membersProgram.methods
+      .setMerchantTier({ merchant: merchant.publicKey })
+      .accountsPartial({ owner: payer, membersStore, merchantInfo, merchantTier })
+      .rpc();
+
+    factoryStoreData = await factoryProgram.account.factoryStore.fetch(factoryStore);
+    const tierNonce = factoryStoreData.mintRequestCounter.toNumber();
+    const [tierRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), new BN(tierNonce).toArrayLike(Buffer, "le", 8)],
       factoryProgram.programId
     );
-    console.log(
-      "New mint request account for rejection:",
-      requestAccount.toString()
+    await factoryProgram.methods
+      .addMintRequest({
+        amount: amount,
+        btcTxid: btcTxid,
+        btcDepositAddress: btcAddress,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount: tierRequest,
+        controllerStore,
+        merchantInfo,
+        merchantTier,
+        custodianBtcAddress,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const tierRequestData = await factoryProgram.account.requestAccount.fetch(tierRequest);
+    assert.equal(tierRequestData.feeBps, 20);
+    const treasuryBefore = new BN(
+      (await provider.connection.getTokenAccountBalance(feeTokenAccount)).value.amount
+    );
+    await factoryProgram.methods
+      .confirmMintRequest({
+        nonce: new BN(tierNonce),
+        requestHash: tierRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        requestAccount: tierRequest,
+        tokenMint,
+        toAddress: merchant.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        tokenAccount: merchantTokenAccount,
+        feeTokenAccount,
+        controllerProgram: controllerProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+
+    const tierFee = amount.muln(20).divn(10000);
+    const treasuryAfter = new BN(
+      (await provider.connection.getTokenAccountBalance(feeTokenAccount)).value.amount
+    );
+    assert.equal(treasuryAfter.sub(treasuryBefore).toString(), tierFee.toString());
+
+    // Remove the merchant from the tier again
+    await membersProgram.methods
+      .setMerchantTier({ merchant: merchant.publicKey })
+      .accountsPartial({ owner: payer, membersStore, merchantInfo, merchantTier: null })
+      .rpc();
+
+    // Disable the fees again for the remaining tests by omitting the fee recipient
+    await factoryProgram.methods
+      .setFeeConfig({
+        mintFeeBps: 0,
+        burnFeeBps: 0,
+        minFee: new BN(0),
+      })
+      .accounts({
+        owner: payer,
+        factoryStore,
+        controllerStore,
+        feeRecipient: null,
+      })
+      .rpc();
+    factoryStoreData = await factoryProgram.account.factoryStore.fetch(factoryStore);
+    assert.equal(factoryStoreData.feeRecipient.toString(), PublicKey.default.toString());
+  });
+
+  it("Queue fee configuration changes behind the controller timelock", async () => {
+    const guardian = Keypair.generate();
+    const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+    const setFees = () =>
+      factoryProgram.methods
+        .setFeeConfig({
+          mintFeeBps: 100,
+          burnFeeBps: 100,
+          minFee: new BN(0),
+        })
+        .accounts({
+          owner: payer,
+          factoryStore,
+          controllerStore,
+          feeRecipient: null,
+        })
+        .rpc();
+    const applyFees = () =>
+      factoryProgram.methods.applyFeeConfig().accounts({ factoryStore }).rpc();
+
+    await controllerProgram.methods
+      .setTimelockConfig({ delay: new BN(2), guardian: guardian.publicKey })
+      .accounts({ owner: payer, controllerStore })
+      .rpc();
+
+    // The change is queued rather than applied
+    await setFees();
+    let factoryStoreData = await factoryProgram.account.factoryStore.fetch(factoryStore);
+    assert.equal(factoryStoreData.mintFeeBps, 0);
+    assert.equal(factoryStoreData.pendingFeeConfig.mintFeeBps, 100);
+    try {
+      await applyFees();
+      assert.fail("Applying before the ETA should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "FeeConfigNotReady");
+    }
+
+    // The owner or guardian can drop it
+    await factoryProgram.methods
+      .cancelFeeConfig()
+      .accounts({ authority: payer, factoryStore, controllerStore })
+      .rpc();
+    try {
+      await applyFees();
+      assert.fail("Applying a canceled change should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "NoPendingFeeConfig");
+    }
+
+    // Anyone applies it once the delay has passed
+    await setFees();
+    await sleep(3000);
+    await applyFees();
+    factoryStoreData = await factoryProgram.account.factoryStore.fetch(factoryStore);
+    assert.equal(factoryStoreData.mintFeeBps, 100);
+    assert.equal(factoryStoreData.burnFeeBps, 100);
+    assert.isNull(factoryStoreData.pendingFeeConfig);
+    assert.equal(factoryStoreData.feeRecipient.toString(), PublicKey.default.toString());
+
+    // Disable the timelock and reset the rates for the remaining tests
+    const store = await controllerProgram.account.controllerStore.fetch(controllerStore);
+    const [timelockOperation] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("timelock_operation"),
+        store.timelockOperationCounter.toArrayLike(Buffer, "le", 8),
+      ],
+      controllerProgram.programId
     );
+    await controllerProgram.methods
+      .queueTimelockOperation({
+        action: { setTimelockConfig: { delay: new BN(0), guardian: PublicKey.default } },
+        eta: new BN(Math.floor(Date.now() / 1000) + 3),
+      })
+      .accounts({
+        owner: payer,
+        controllerStore,
+        timelockOperation,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await sleep(5000);
+    await controllerProgram.methods
+      .executeTimelockOperation()
+      .accounts({ executor: payer, controllerStore, timelockOperation, proposer: payer })
+      .rpc();
+    await factoryProgram.methods
+      .setFeeConfig({ mintFeeBps: 0, burnFeeBps: 0, minFee: new BN(0) })
+      .accounts({ owner: payer, factoryStore, controllerStore, feeRecipient: null })
+      .rpc();
+  });
 
+  it("Partially confirm mint request by custodian", async () => {
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const nonce = factoryStoreData.mintRequestCounter.toNumber();
+    const [mintRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
     await factoryProgram.methods
       .addMintRequest({
         amount: amount,
@@ -538,43 +981,1093 @@ describe("factory", () => {
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
-        requestAccount,
+        requestAccount: mintRequest,
         controllerStore,
         merchantInfo,
         custodianBtcAddress,
-        membersProgram: membersProgram.programId,
         systemProgram: SystemProgram.programId,
       })
       .signers([merchant])
       .rpc();
-    console.log("New mint request added for rejection");
 
-    // Custodian rejects request
-    let rejectRequestData = await factoryProgram.account.requestAccount.fetch(
-      requestAccount
+    // Only part of the requested BTC arrived
+    const depositedAmount = amount.divn(2);
+    const balanceBefore = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    const mintRequestData = await factoryProgram.account.requestAccount.fetch(
+      mintRequest
     );
-
     await factoryProgram.methods
-      .rejectMintRequest({
-        nonce: new BN(newMintRequestNonce),
-        requestHash: rejectRequestData.hash,
+      .confirmMintRequestPartial({
+        nonce: new BN(nonce),
+        requestHash: mintRequestData.hash,
+        amount: depositedAmount,
+        reason: "deposit short of requested amount",
       })
       .accounts({
         payer: custodian.publicKey,
         factoryStore,
-        requestAccount,
         controllerStore,
         membersStore,
+        requestAccount: mintRequest,
+        tokenMint,
+        toAddress: merchant.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        tokenAccount: merchantTokenAccount,
+        controllerProgram: controllerProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([custodian])
       .rpc();
-    console.log("Mint request rejected");
 
-    // Verify request status updated to rejected
+    // Both the requested and the minted amounts are kept on the request
     const requestData = await factoryProgram.account.requestAccount.fetch(
-      requestAccount
+      mintRequest
+    );
+    assert.equal(requestData.status.partiallyApproved !== undefined, true);
+    assert.equal(requestData.amount.toString(), amount.toString());
+    assert.equal(requestData.confirmedAmount.toString(), depositedAmount.toString());
+
+    const balanceAfter = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    assert.equal(balanceAfter.sub(balanceBefore).toString(), depositedAmount.toString());
+  });
+
+  it("Confirm and reject mint requests in batches", async () => {
+    const [merchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_requests"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+
+    // Open four mint requests, two to confirm and two to reject
+    const requests: { nonce: BN; requestHash: number[]; pda: PublicKey }[] = [];
+    for (let i = 0; i < 4; i++) {
+      const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+        factoryStore
+      );
+      const nonce = factoryStoreData.mintRequestCounter;
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mint_request"), nonce.toArrayLike(Buffer, "le", 8)],
+        factoryProgram.programId
+      );
+      await factoryProgram.methods
+        .addMintRequest({
+          amount: amount,
+          btcTxid: btcTxid,
+          btcDepositAddress: btcAddress,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: pda,
+          controllerStore,
+          merchantInfo,
+          custodianBtcAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      const requestData = await factoryProgram.account.requestAccount.fetch(pda);
+      requests.push({ nonce, requestHash: requestData.hash, pda });
+    }
+    const toConfirm = requests.slice(0, 2);
+    const toReject = requests.slice(2);
+
+    const balanceBefore = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    await factoryProgram.methods
+      .confirmMintRequests({
+        requests: toConfirm.map(({ nonce, requestHash }) => ({ nonce, requestHash })),
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        tokenMint,
+        controllerProgram: controllerProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        toConfirm.flatMap(({ pda }) => [
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: merchantRequests, isSigner: false, isWritable: true },
+          { pubkey: merchantTokenAccount, isSigner: false, isWritable: true },
+          {
+            pubkey: getDenylistEntry(membersProgram, merchant.publicKey),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+      )
+      .signers([custodian])
+      .rpc();
+
+    await factoryProgram.methods
+      .rejectMintRequests({
+        requests: toReject.map(({ nonce, requestHash }) => ({ nonce, requestHash })),
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+      })
+      .remainingAccounts(
+        toReject.flatMap(({ pda }) => [
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: merchantRequests, isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([custodian])
+      .rpc();
+
+    for (const { pda } of toConfirm) {
+      const requestData = await factoryProgram.account.requestAccount.fetch(pda);
+      assert.equal(requestData.status.approved !== undefined, true);
+    }
+    for (const { pda } of toReject) {
+      const requestData = await factoryProgram.account.requestAccount.fetch(pda);
+      assert.equal(requestData.status.rejected !== undefined, true);
+    }
+    const balanceAfter = new BN(
+      (await provider.connection.getTokenAccountBalance(merchantTokenAccount)).value.amount
+    );
+    assert.equal(balanceAfter.sub(balanceBefore).toString(), amount.muln(2).toString());
+
+    const merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(merchantRequests);
+    assert.equal(merchantRequestsData.pendingMintRequests.toNumber(), 0);
+  });
+
+  it("Settle burn request across several BTC payouts", async () => {
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const nonce = factoryStoreData.burnRequestCounter.toNumber();
+    const [burnRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_request"), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    const burnAmount = new BN(10000);
+    await factoryProgram.methods
+      .burn({ amount: burnAmount })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount: burnRequest,
+        controllerStore,
+        merchantInfo,
+        merchantBtcAddress,
+        tokenMint,
+        tokenAccount: merchantTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    const burnRequestData = await factoryProgram.account.requestAccount.fetch(
+      burnRequest
+    );
+
+    const [burnPayouts] = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_payouts"), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    const recordPayout = (txid: string, sent: BN, fee: BN) =>
+      factoryProgram.methods
+        .recordBurnPayout({
+          nonce: new BN(nonce),
+          btcTxid: txid,
+          btcAmountSent: sent,
+          btcNetworkFee: fee,
+          requestHash: burnRequestData.hash,
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          requestAccount: burnRequest,
+          burnPayouts,
+          controllerStore,
+          membersStore,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([custodian])
+        .rpc();
+
+    // First payout leaves the burn pending
+    const firstTxid = "a".repeat(64);
+    await recordPayout(firstTxid, new BN(6000), new BN(500));
+    let requestData = await factoryProgram.account.requestAccount.fetch(
+      burnRequest
+    );
+    assert.equal(requestData.status.pending !== undefined, true);
+    let payoutsData = await factoryProgram.account.burnPayouts.fetch(burnPayouts);
+    assert.equal(payoutsData.payouts.length, 1);
+
+    // The same transaction can't be recorded twice
+    try {
+      await recordPayout(firstTxid, new BN(1000), new BN(0));
+      assert.fail("A repeated payout should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "DuplicateBtcPayout");
+    }
+
+    // Second payout covers the rest of the burn
+    await recordPayout(btcTxid, new BN(3000), new BN(500));
+    requestData = await factoryProgram.account.requestAccount.fetch(burnRequest);
+    assert.equal(requestData.status.approved !== undefined, true);
+    payoutsData = await factoryProgram.account.burnPayouts.fetch(burnPayouts);
+    assert.equal(payoutsData.payouts.length, 2);
+    assert.equal(payoutsData.payouts[0].btcTxid, firstTxid);
+    assert.equal(requestData.btcAmountSent.toString(), "9000");
+    assert.equal(requestData.btcNetworkFee.toString(), "1000");
+    assert.equal(requestData.btcTxid, btcTxid);
+  });
+
+  it("Confirm burn requests paid out by one BTC transaction", async () => {
+    const [merchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_requests"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const burnAmount = new BN(10000);
+
+    // Open two burn requests
+    const requests: { nonce: BN; requestHash: number[]; pda: PublicKey }[] = [];
+    for (let i = 0; i < 2; i++) {
+      const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+        factoryStore
+      );
+      const nonce = factoryStoreData.burnRequestCounter;
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("burn_request"), nonce.toArrayLike(Buffer, "le", 8)],
+        factoryProgram.programId
+      );
+      await factoryProgram.methods
+        .burn({ amount: burnAmount })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: pda,
+          controllerStore,
+          merchantInfo,
+          merchantBtcAddress,
+          tokenMint,
+          tokenAccount: merchantTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      const requestData = await factoryProgram.account.requestAccount.fetch(pda);
+      requests.push({ nonce, requestHash: requestData.hash, pda });
+    }
+
+    // Each burn is paid by one output of the transaction, the miner fee split between them
+    const confirmBatch = (vouts: number[]) =>
+      factoryProgram.methods
+        .confirmBurnRequests({
+          btcTxid: btcTxid,
+          requests: requests.map(({ nonce, requestHash }, i) => ({
+            nonce,
+            requestHash,
+            vout: vouts[i],
+            btcAmountSent: burnAmount.sub(btcNetworkFee.divn(2)),
+            btcNetworkFee: btcNetworkFee.divn(2),
+          })),
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          controllerStore,
+          membersStore,
+        })
+        .remainingAccounts(
+          requests.flatMap(({ pda }) => [
+            { pubkey: pda, isSigner: false, isWritable: true },
+            { pubkey: merchantRequests, isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([custodian])
+        .rpc();
+
+    // The same output can't settle both burns
+    try {
+      await confirmBatch([0, 0]);
+      assert.fail("Duplicate outputs should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "DuplicateBtcVout");
+    }
+
+    await confirmBatch([0, 1]);
+
+    for (const [vout, { pda }] of requests.entries()) {
+      const requestData = await factoryProgram.account.requestAccount.fetch(pda);
+      assert.equal(requestData.status.approved !== undefined, true);
+      assert.equal(requestData.btcTxid, btcTxid);
+      assert.equal(requestData.btcVout, vout);
+    }
+  });
+
+  it("Mint to a recipient on the merchant allowlist", async () => {
+    const [mintRecipientAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_recipient_allowlist"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    await factoryProgram.methods
+      .addMintRecipient({ recipient: customer.publicKey })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore,
+        controllerStore,
+        merchantInfo,
+        mintRecipientAllowlist,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const nonce = factoryStoreData.mintRequestCounter.toNumber();
+    const [mintRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    await factoryProgram.methods
+      .addMintRequest({
+        amount: amount,
+        btcTxid: btcTxid,
+        btcDepositAddress: btcAddress,
+        recipient: customer.publicKey,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount: mintRequest,
+        controllerStore,
+        merchantInfo,
+        custodianBtcAddress,
+        mintRecipientAllowlist,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const mintRequestData = await factoryProgram.account.requestAccount.fetch(
+      mintRequest
+    );
+    assert.equal(mintRequestData.recipient.toString(), customer.publicKey.toString());
+
+    const customerTokenAccount = getAssociatedTokenAddressSync(
+      tokenMint,
+      customer.publicKey
+    );
+    await factoryProgram.methods
+      .confirmMintRequest({
+        nonce: new BN(nonce),
+        requestHash: mintRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        requestAccount: mintRequest,
+        tokenMint,
+        toAddress: customer.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, customer.publicKey),
+        tokenAccount: customerTokenAccount,
+        controllerProgram: controllerProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(customerTokenAccount)).value.amount,
+      amount.toString()
+    );
+
+    // Recipients off the allowlist are refused
+    await factoryProgram.methods
+      .removeMintRecipient({ recipient: customer.publicKey })
+      .accounts({
+        payer: merchant.publicKey,
+        mintRecipientAllowlist,
+      })
+      .signers([merchant])
+      .rpc();
+    const [nextMintRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), new BN(nonce + 1).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    try {
+      await factoryProgram.methods
+        .addMintRequest({
+          amount: amount,
+          btcTxid: btcTxid,
+          btcDepositAddress: btcAddress,
+          recipient: customer.publicKey,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: nextMintRequest,
+          controllerStore,
+          merchantInfo,
+          custodianBtcAddress,
+          mintRecipientAllowlist,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      assert.fail("Should have thrown an error");
+    } catch (error) {
+      assert.match(error.message, /RecipientNotAllowed/);
+    }
+  });
+
+  it("Burn to a BTC payout address on the merchant allowlist", async () => {
+    const payoutAddress = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+    const [btcPayoutAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from("btc_payout_allowlist"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    await factoryProgram.methods
+      .addBtcPayoutAddress({ btcAddress: payoutAddress })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore,
+        controllerStore,
+        merchantInfo,
+        btcPayoutAllowlist,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const nonce = factoryStoreData.burnRequestCounter.toNumber();
+    const [burnRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_request"), new BN(nonce).toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    const burnAmount = new BN(10000);
+    await factoryProgram.methods
+      .burn({ amount: burnAmount, btcAddress: payoutAddress })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount: burnRequest,
+        controllerStore,
+        merchantInfo,
+        // not needed when paying out to an allowlisted address
+        merchantBtcAddress: null,
+        btcPayoutAllowlist,
+        tokenMint,
+        tokenAccount: merchantTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    // The payout address is stored on the request instead of the default one
+    const burnRequestData = await factoryProgram.account.requestAccount.fetch(
+      burnRequest
+    );
+    assert.equal(burnRequestData.btcDepositAddress, payoutAddress);
+
+    await factoryProgram.methods
+      .confirmBurnRequest({
+        nonce: new BN(nonce),
+        btcTxid: btcTxid,
+        btcAmountSent: burnAmount.sub(btcNetworkFee),
+        btcNetworkFee: btcNetworkFee,
+        requestHash: burnRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        requestAccount: burnRequest,
+        controllerStore,
+        membersStore,
+      })
+      .signers([custodian])
+      .rpc();
+  });
+
+  it("Cancel mint request by merchant", async () => {
+    // First create a new mint request
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const newMintRequestNonce = factoryStoreData.mintRequestCounter.toNumber();
+    console.log("New mint request nonce:", newMintRequestNonce);
+
+    [requestAccount, requestAccountBump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from("mint_request"),
+        new BN(newMintRequestNonce).toArrayLike(Buffer, "le", 8),
+      ],
+      factoryProgram.programId
+    );
+    console.log("New mint request account:", requestAccount.toString());
+
+    await factoryProgram.methods
+      .addMintRequest({
+        amount: amount,
+        btcTxid: btcTxid,
+        btcDepositAddress: btcAddress,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount,
+        controllerStore,
+        merchantInfo,
+        custodianBtcAddress,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("New mint request added");
+
+    // Now cancel this request
+    let cancelRequestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+
+    await factoryProgram.methods
+      .cancelMintRequest({
+        nonce: new BN(newMintRequestNonce),
+        requestHash: cancelRequestData.hash,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore,
+        requestAccount,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("Mint request canceled");
+
+    // Verify request status updated to canceled
+    const requestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+    assert.equal(requestData.status.canceled !== undefined, true);
+  });
+
+  it("Reject mint request by custodian", async () => {
+    // First create a new mint request
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const newMintRequestNonce = factoryStoreData.mintRequestCounter.toNumber();
+    console.log("New mint request nonce for rejection:", newMintRequestNonce);
+
+    [requestAccount, requestAccountBump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from("mint_request"),
+        new BN(newMintRequestNonce).toArrayLike(Buffer, "le", 8),
+      ],
+      factoryProgram.programId
+    );
+    console.log(
+      "New mint request account for rejection:",
+      requestAccount.toString()
+    );
+
+    await factoryProgram.methods
+      .addMintRequest({
+        amount: amount,
+        btcTxid: btcTxid,
+        btcDepositAddress: btcAddress,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount,
+        controllerStore,
+        merchantInfo,
+        custodianBtcAddress,
+        membersProgram: membersProgram.programId,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("New mint request added for rejection");
+
+    // Custodian rejects request
+    let rejectRequestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+
+    await factoryProgram.methods
+      .rejectMintRequest({
+        nonce: new BN(newMintRequestNonce),
+        requestHash: rejectRequestData.hash,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        requestAccount,
+        controllerStore,
+        membersStore,
+      })
+      .signers([custodian])
+      .rpc();
+    console.log("Mint request rejected");
+
+    // Verify request status updated to rejected
+    const requestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+    assert.equal(requestData.status.rejected !== undefined, true);
+  });
+
+  it("Release a canceled mint request from the tier daily cap", async () => {
+    const [merchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_requests"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const dailyMintAmount = (
+      await factoryProgram.account.merchantRequests.fetch(merchantRequests)
+    ).dailyMintAmount;
+
+    // A tier with room for a single more request today
+    const [merchantTier] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_tier"), Buffer.from("capped")],
+      membersProgram.programId
+    );
+    await membersProgram.methods
+      .createMerchantTier({
+        name: "capped",
+        maxRequestAmount: amount,
+        dailyCap: dailyMintAmount.add(amount),
+        feeBps: 0,
+        burnsAllowed: true,
+      })
+      .accounts({
+        owner: payer,
+        membersStore,
+        merchantTier,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await membersProgram.methods
+      .setMerchantTier({ merchant: merchant.publicKey })
+      .accountsPartial({ owner: payer, membersStore, merchantInfo, merchantTier })
+      .rpc();
+
+    const addMintRequest = async () => {
+      const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+        factoryStore
+      );
+      const nonce = factoryStoreData.mintRequestCounter;
+      const [cappedRequest] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mint_request"), nonce.toArrayLike(Buffer, "le", 8)],
+        factoryProgram.programId
+      );
+      await factoryProgram.methods
+        .addMintRequest({
+          amount: amount,
+          btcTxid: btcTxid,
+          btcDepositAddress: btcAddress,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount: cappedRequest,
+          controllerStore,
+          merchantInfo,
+          merchantTier,
+          custodianBtcAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc();
+      return { nonce, cappedRequest };
+    };
+    const cancelMintRequest = async (nonce: BN, cappedRequest: PublicKey) => {
+      const requestData = await factoryProgram.account.requestAccount.fetch(
+        cappedRequest
+      );
+      await factoryProgram.methods
+        .cancelMintRequest({ nonce, requestHash: requestData.hash })
+        .accounts({
+          payer: merchant.publicKey,
+          factoryStore,
+          requestAccount: cappedRequest,
+        })
+        .signers([merchant])
+        .rpc();
+    };
+
+    // The canceled amount is released, so the merchant can open it again
+    const first = await addMintRequest();
+    await cancelMintRequest(first.nonce, first.cappedRequest);
+    let merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(merchantRequests);
+    assert.equal(
+      merchantRequestsData.dailyMintAmount.toString(),
+      dailyMintAmount.toString()
+    );
+
+    const second = await addMintRequest();
+    merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(merchantRequests);
+    assert.equal(
+      merchantRequestsData.dailyMintAmount.toString(),
+      dailyMintAmount.add(amount).toString()
+    );
+
+    // while the reopened request is pending the cap is reached
+    try {
+      await addMintRequest();
+      assert.fail("Exceeding the tier daily cap should fail");
+    } catch (err) {
+      assert.include(err.toString(), "TierDailyCapExceeded");
+    }
+
+    // Settle the request and remove the merchant from the tier again
+    await cancelMintRequest(second.nonce, second.cappedRequest);
+    await membersProgram.methods
+      .setMerchantTier({ merchant: merchant.publicKey })
+      .accountsPartial({ owner: payer, membersStore, merchantInfo, merchantTier: null })
+      .rpc();
+  });
+
+  it("Migrate a mint request in the original layout", async () => {
+    // Pending mint request preloaded from tests/fixtures/legacy_mint_request.json
+    const legacyNonce = new BN(1000000);
+    const [legacyRequest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_request"), legacyNonce.toArrayLike(Buffer, "le", 8)],
+      factoryProgram.programId
+    );
+    const legacyRequester = new PublicKey(
+      "6vUahYMakZLAUaCHBmsCGtzK1NAsrJQ8MyMZfTRXxtCa"
+    );
+    const legacyHash =
+      "245bd9e6c79a58cfd38e01d667ff38296024bb623e6587f504a03b7ff4260975";
+    const [legacyMerchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_requests"), legacyRequester.toBuffer()],
+      factoryProgram.programId
+    );
+    const legacyInfo = await provider.connection.getAccountInfo(legacyRequest);
+    assert.equal(legacyInfo.data.length, 271);
+
+    const migrate = () =>
+      factoryProgram.methods
+        .migrateRequest({
+          requestType: { mint: {} },
+          nonce: legacyNonce,
+          requester: legacyRequester,
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          controllerStore,
+          membersStore,
+          requestAccount: legacyRequest,
+          merchantRequests: legacyMerchantRequests,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([custodian])
+        .rpc();
+
+    await migrate();
+    console.log("Legacy mint request migrated");
+
+    // The request now decodes with the current layout
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const requestData = await factoryProgram.account.requestAccount.fetch(
+      legacyRequest
+    );
+    assert.equal(requestData.nonce.toString(), legacyNonce.toString());
+    assert.equal(requestData.requester.toString(), legacyRequester.toString());
+    assert.equal(requestData.recipient.toString(), legacyRequester.toString());
+    assert.equal(requestData.amount.toNumber(), 100000);
+    assert.equal(requestData.btcDepositTimestamp.toNumber(), 0);
+    assert.equal(requestData.feeBps, factoryStoreData.mintFeeBps);
+    assert.equal(requestData.confirmedAmount.toNumber(), 0);
+    assert.equal(requestData.status.pending !== undefined, true);
+    // the hash covers the new fields and is recomputed
+    assert.notEqual(Buffer.from(requestData.hash).toString("hex"), legacyHash);
+
+    try {
+      await migrate();
+      assert.fail("Migrating a request twice should fail");
+    } catch (err) {
+      assert.include(err.toString(), "RequestAlreadyMigrated");
+    }
+
+    // The pending request is counted against its merchant, only once
+    let merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(legacyMerchantRequests);
+    assert.equal(merchantRequestsData.merchant.toString(), legacyRequester.toString());
+    assert.equal(merchantRequestsData.pendingMintRequests.toNumber(), 1);
+
+    // and settles like any other request, with the recomputed hash
+    await factoryProgram.methods
+      .rejectMintRequest({ nonce: legacyNonce, requestHash: requestData.hash })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        requestAccount: legacyRequest,
+        controllerStore,
+        membersStore,
+      })
+      .signers([custodian])
+      .rpc();
+    merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(legacyMerchantRequests);
+    assert.equal(merchantRequestsData.pendingMintRequests.toNumber(), 0);
+  });
+
+  it("Rotate custodian BTC deposit address", async () => {
+    const newBtcAddress = "bc1q9h6tq79mxxazfz3m6j2ylc6c8t8rlj7ep8vvt0";
+    const newCustodianBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      merchant.publicKey,
+      1
+    );
+
+    // Custodian activates a second address, the first one stays on chain
+    await factoryProgram.methods
+      .setCustodianBtcDepositAddress({
+        merchant: merchant.publicKey,
+        btcDepositAddress: newBtcAddress,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        merchantInfo,
+        custodianBtcAddressBook,
+        custodianBtcAddress: newCustodianBtcAddress,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+    console.log("Second custodian BTC deposit address set");
+
+    const addressBookData =
+      await factoryProgram.account.custodianBtcAddressBook.fetch(
+        custodianBtcAddressBook
+      );
+    assert.equal(addressBookData.addressCount, 2);
+    const newAddressData =
+      await factoryProgram.account.custodianBtcDepositAddress.fetch(
+        newCustodianBtcAddress
+      );
+    assert.equal(newAddressData.index, 1);
+    assert.equal(newAddressData.btcAddress, newBtcAddress);
+    assert.isTrue(newAddressData.active);
+
+    // Then retires the first one
+    await factoryProgram.methods
+      .retireCustodianBtcDepositAddress({
+        merchant: merchant.publicKey,
+        index: 0,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        custodianBtcAddress,
+      })
+      .signers([custodian])
+      .rpc();
+    console.log("First custodian BTC deposit address retired");
+
+    const oldAddressData =
+      await factoryProgram.account.custodianBtcDepositAddress.fetch(
+        custodianBtcAddress
+      );
+    assert.isFalse(oldAddressData.active);
+    assert.isTrue(oldAddressData.retiredAt.gt(oldAddressData.activatedAt));
+
+    // A deposit sent to the old address while it was active is still accepted
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    const nonce = factoryStoreData.mintRequestCounter.toNumber();
+    [requestAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_request"),
+        new BN(nonce).toArrayLike(Buffer, "le", 8),
+      ],
+      factoryProgram.programId
+    );
+
+    await factoryProgram.methods
+      .addMintRequest({
+        amount: amount,
+        btcTxid: btcTxid,
+        btcDepositAddress: btcAddress,
+        btcDepositTimestamp: oldAddressData.activatedAt,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+        factoryStore,
+        requestAccount,
+        controllerStore,
+        merchantInfo,
+        custodianBtcAddress,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+    console.log("Mint request against the retired address added");
+
+    const requestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+    assert.equal(requestData.status.pending !== undefined, true);
+    assert.equal(requestData.btcDepositAddress, btcAddress);
+    // The claimed deposit time is kept for the custodian to verify
+    assert.equal(
+      requestData.btcDepositTimestamp.toString(),
+      oldAddressData.activatedAt.toString()
+    );
+
+    // Cancel it again so the merchant can resign
+    await factoryProgram.methods
+      .cancelMintRequest({
+        nonce: new BN(nonce),
+        requestHash: requestData.hash,
+      })
+      .accounts({
+        payer: merchant.publicKey,
+        factoryStore,
+        requestAccount,
+      })
+      .signers([merchant])
+      .rpc();
+  });
+
+  it("Resign merchant", async () => {
+    // Every request opened above has been settled
+    const [merchantRequests] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merchant_requests"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const merchantRequestsData =
+      await factoryProgram.account.merchantRequests.fetch(merchantRequests);
+    assert.equal(merchantRequestsData.pendingMintRequests.toNumber(), 0);
+    assert.equal(merchantRequestsData.pendingBurnRequests.toNumber(), 0);
+    const [mintRecipientAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_recipient_allowlist"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const [btcPayoutAllowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from("btc_payout_allowlist"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const addressBookData =
+      await factoryProgram.account.custodianBtcAddressBook.fetch(
+        custodianBtcAddressBook
+      );
+    const custodianBtcAddresses = [...Array(addressBookData.addressCount).keys()].map(
+      (index) => getCustodianBtcAddress(factoryProgram, merchant.publicKey, index)
+    );
+
+    const membersStoreBefore = await membersProgram.account.membersStore.fetch(
+      membersStore
+    );
+
+    const resign = (allowlist: PublicKey) =>
+      factoryProgram.methods
+        .resignMerchant()
+        .accountsPartial({
+          payer: merchant.publicKey,
+          factoryStore,
+          controllerStore,
+          membersStore,
+          membersOwner: membersStoreBefore.owner,
+          custodian: membersStoreBefore.custodian,
+          merchantInfo,
+          merchantRequests,
+          custodianBtcAddressBook,
+          merchantBtcAddress,
+          mintRecipientAllowlist: allowlist,
+          btcPayoutAllowlist,
+          membersProgram: membersProgram.programId,
+          systemProgram: SystemProgram.programId,
+        })
+        // every indexed deposit address is closed along with the book
+        .remainingAccounts(
+          custodianBtcAddresses.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([merchant])
+        .rpc();
+
+    // An existing PDA can't be kept open by passing another account in its place
+    try {
+      await resign(Keypair.generate().publicKey);
+      assert.fail("Resigning with a substituted allowlist should be rejected");
+    } catch (err) {
+      assert.include(err.toString(), "ConstraintSeeds");
+    }
+
+    await resign(mintRecipientAllowlist);
+    console.log("Merchant resigned");
+
+    // Verify merchant registration and factory accounts are closed
+    for (const account of [
+      merchantInfo,
+      merchantRequests,
+      custodianBtcAddressBook,
+      ...custodianBtcAddresses,
+      merchantBtcAddress,
+      mintRecipientAllowlist,
+      btcPayoutAllowlist,
+    ]) {
+      assert.isNull(await provider.connection.getAccountInfo(account));
+    }
+    const membersStoreAfter = await membersProgram.account.membersStore.fetch(
+      membersStore
+    );
+    assert.equal(
+      membersStoreAfter.merchantCount.toNumber(),
+      membersStoreBefore.merchantCount.toNumber() - 1
     );
-    assert.equal(requestData.status.rejected !== undefined, true);
   });
 });
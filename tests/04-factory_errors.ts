@@ -23,8 +23,12 @@ import {
   initializeController,
   initializeFactory,
   configureController,
+  configureMembers,
   setCustodian,
   addMerchant,
+  getDenylistEntry,
+  getCustodianBtcAddressBook,
+  getCustodianBtcAddress,
   sharedState,
 } from "../helpers/test-setup";
 
@@ -64,7 +68,7 @@ describe("factory_error_tests", () => {
   let controllerStore: PublicKey;
   let factoryStore: PublicKey;
   let mintAccount: PublicKey;
-  let custodianBtcAddress: PublicKey, custodianBtcAddressBump: number;
+  let custodianBtcAddressBook: PublicKey, custodianBtcAddress: PublicKey;
   let merchantBtcAddress: PublicKey, merchantBtcAddressBump: number;
   let requestAccount: PublicKey, requestAccountBump: number;
   let tokenMint: PublicKey;
@@ -81,6 +85,7 @@ describe("factory_error_tests", () => {
   const wrongBtcAddress = "bc1qwrongaddress123456789abcdefghijklmnopqrstu";
   const btcTxid =
     "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16";
+  const btcNetworkFee = new BN(2000); // BTC miner fee paid on burn payouts
   const amount = new BN(100000000); // 1 BTC = 100,000,000 satoshis
   const zeroAmount = new BN(0);
   let mintRequestNonce = 0;
@@ -149,6 +154,9 @@ describe("factory_error_tests", () => {
       wallet
     );
 
+    // Allow the Factory to act on the Members registry
+    await configureMembers(membersProgram, factoryProgram.programId, wallet);
+
     // Calculate token account addresses
     merchantTokenAccount = getAssociatedTokenAddressSync(
       tokenMint,
@@ -180,7 +188,10 @@ describe("factory_error_tests", () => {
       membersStore
     );
     console.log("Custodian set to:", membersStoreData.custodian.toString());
-    console.log("Merchant count:", membersStoreData.merchantCount);
+    console.log(
+      "Merchant count:",
+      membersStoreData.merchantCount.toString()
+    );
   });
 
   // Mint error test cases
@@ -213,6 +224,7 @@ describe("factory_error_tests", () => {
         })
         .accounts({
           payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           factoryStore: factoryStore,
           requestAccount: requestAccount,
           controllerStore: controllerStore,
@@ -244,12 +256,16 @@ describe("factory_error_tests", () => {
       await new Promise((resolve) => setTimeout(resolve, 500)); // Wait for confirmation
     }
 
-    // Derive custodian BTC address account
-    [custodianBtcAddress, custodianBtcAddressBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("custodian_btc_address"), merchant.publicKey.toBuffer()],
-        factoryProgram.programId
-      );
+    // Derive custodian BTC address accounts, the first address gets index 0
+    custodianBtcAddressBook = getCustodianBtcAddressBook(
+      factoryProgram,
+      merchant.publicKey
+    );
+    custodianBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      merchant.publicKey,
+      0
+    );
     console.log("Custodian BTC address PDA:", custodianBtcAddress.toString());
 
     // Ensure custodian is the one set in members_store
@@ -277,6 +293,7 @@ describe("factory_error_tests", () => {
         controllerStore: controllerStore,
         membersStore: membersStore,
         merchantInfo: merchantInfo,
+        custodianBtcAddressBook,
         custodianBtcAddress: custodianBtcAddress,
         membersProgram: membersProgram.programId,
         systemProgram: SystemProgram.programId,
@@ -287,18 +304,183 @@ describe("factory_error_tests", () => {
     console.log("Setting BTC address:", btcAddress);
   });
 
+  it("Attempt to mint against a retired custodian BTC address", async () => {
+    const retiredBtcAddress = "bc1q9h6tq79mxxazfz3m6j2ylc6c8t8rlj7ep8vvt0";
+    const retiredCustodianBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      merchant.publicKey,
+      1
+    );
+
+    // Activate and immediately retire a second address, index 0 stays active
+    await factoryProgram.methods
+      .setCustodianBtcDepositAddress({
+        merchant: merchant.publicKey,
+        btcDepositAddress: retiredBtcAddress,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        merchantInfo,
+        custodianBtcAddressBook,
+        custodianBtcAddress: retiredCustodianBtcAddress,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([custodian])
+      .rpc();
+    await factoryProgram.methods
+      .retireCustodianBtcDepositAddress({
+        merchant: merchant.publicKey,
+        index: 1,
+      })
+      .accounts({
+        payer: custodian.publicKey,
+        factoryStore,
+        controllerStore,
+        membersStore,
+        custodianBtcAddress: retiredCustodianBtcAddress,
+      })
+      .signers([custodian])
+      .rpc();
+
+    // Retiring twice is rejected
+    const retireError = await catchError(
+      factoryProgram.methods
+        .retireCustodianBtcDepositAddress({
+          merchant: merchant.publicKey,
+          index: 1,
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          controllerStore,
+          membersStore,
+          custodianBtcAddress: retiredCustodianBtcAddress,
+        })
+        .signers([custodian])
+        .rpc()
+    );
+    assert.notEqual(retireError, null, "Should have thrown an error but didn't");
+    assert.match(retireError.message, /BtcDepositAddressRetired/);
+
+    const factoryStoreData = await factoryProgram.account.factoryStore.fetch(
+      factoryStore
+    );
+    [requestAccount, requestAccountBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_request"),
+        factoryStoreData.mintRequestCounter.toArrayLike(Buffer, "le", 8),
+      ],
+      factoryProgram.programId
+    );
+
+    // A deposit made now, after retirement, is not accepted
+    const error = await catchError(
+      factoryProgram.methods
+        .addMintRequest({
+          amount: amount,
+          btcTxid: btcTxid,
+          btcDepositAddress: retiredBtcAddress,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount,
+          controllerStore,
+          merchantInfo,
+          custodianBtcAddress: retiredCustodianBtcAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc()
+    );
+    assert.notEqual(error, null, "Should have thrown an error but didn't");
+    console.log("Retired address error:", error.message);
+    assert.match(error.message, /BtcDepositAddressNotActive/);
+
+    // Nor is a deposit backdated to before the address was activated
+    const retiredAddressData =
+      await factoryProgram.account.custodianBtcDepositAddress.fetch(
+        retiredCustodianBtcAddress
+      );
+    const backdatedError = await catchError(
+      factoryProgram.methods
+        .addMintRequest({
+          amount: amount,
+          btcTxid: btcTxid,
+          btcDepositAddress: retiredBtcAddress,
+          btcDepositTimestamp: retiredAddressData.activatedAt.subn(3600),
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount,
+          controllerStore,
+          merchantInfo,
+          custodianBtcAddress: retiredCustodianBtcAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc()
+    );
+    assert.notEqual(backdatedError, null, "Should have thrown an error but didn't");
+    assert.match(backdatedError.message, /BtcDepositAddressNotActive/);
+  });
+
+  it("Attempt to migrate a custodian BTC deposit address without a legacy account", async () => {
+    const [legacyCustodianBtcAddress] = PublicKey.findProgramAddressSync(
+      [Buffer.from("custodian_btc_address"), merchant.publicKey.toBuffer()],
+      factoryProgram.programId
+    );
+    const error = await catchError(
+      factoryProgram.methods
+        .migrateCustodianBtcDepositAddress({ merchant: merchant.publicKey })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          controllerStore,
+          membersStore,
+          legacyCustodianBtcAddress,
+          custodianBtcAddressBook,
+          custodianBtcAddress: getCustodianBtcAddress(factoryProgram, merchant.publicKey, 0),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([custodian])
+        .rpc()
+    );
+    assert.notEqual(error, null, "Should have thrown an error but didn't");
+    assert.match(error.message, /ConstraintOwner|AccountOwnedByWrongProgram/);
+  });
+
+  it("Attempt to migrate a factory store already in the current layout", async () => {
+    const error = await catchError(
+      factoryProgram.methods
+        .migrateFactoryStore()
+        .accounts({
+          owner: payer,
+          factoryStore,
+          controllerStore,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc()
+    );
+    assert.notEqual(error, null, "Should have thrown an error but didn't");
+    assert.match(error.message, /AlreadyMigrated/);
+  });
+
   it("Attempt to mint to non-merchant address", async () => {
     const btcAddress = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh";
     console.log("Setting BTC address:", btcAddress);
     // Derive a BTC address PDA for non-merchant
-    const [nonMerchantBtcAddress, nonMerchantBtcAddressBump] =
-      PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("custodian_btc_address"),
-          nonMerchant.publicKey.toBuffer(),
-        ],
-        factoryProgram.programId
-      );
+    const nonMerchantBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      nonMerchant.publicKey,
+      0
+    );
 
     // Derive merchant info account
     [merchantInfo, merchantInfoBump] = PublicKey.findProgramAddressSync(
@@ -353,6 +535,7 @@ describe("factory_error_tests", () => {
         })
         .accounts({
           payer: nonMerchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, nonMerchant.publicKey),
           factoryStore: factoryStore,
           requestAccount: requestAccount,
           controllerStore: controllerStore,
@@ -388,11 +571,11 @@ describe("factory_error_tests", () => {
     );
 
     // Derive a custodianBtcAddress for admin
-    const [adminCustodianBtcAddress, adminCustodianBtcAddressBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("custodian_btc_address"), admin.publicKey.toBuffer()],
-        factoryProgram.programId
-      );
+    const adminCustodianBtcAddress = getCustodianBtcAddress(
+      factoryProgram,
+      admin.publicKey,
+      0
+    );
 
     // Derive merchant info account
     [merchantInfo, merchantInfoBump] = PublicKey.findProgramAddressSync(
@@ -412,6 +595,10 @@ describe("factory_error_tests", () => {
         controllerStore: controllerStore,
         membersStore: membersStore,
         merchantInfo: merchantInfo,
+        custodianBtcAddressBook: getCustodianBtcAddressBook(
+          factoryProgram,
+          admin.publicKey
+        ),
         custodianBtcAddress: adminCustodianBtcAddress,
         systemProgram: SystemProgram.programId,
       })
@@ -427,6 +614,7 @@ describe("factory_error_tests", () => {
       })
       .accounts({
         payer: admin.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, admin.publicKey),
         factoryStore: factoryStore,
         requestAccount: requestAccount,
         membersStore: membersStore,
@@ -497,6 +685,7 @@ describe("factory_error_tests", () => {
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore: factoryStore,
         requestAccount: requestAccount,
         merchantInfo: merchantInfo,
@@ -526,6 +715,7 @@ describe("factory_error_tests", () => {
           membersStore,
           tokenMint,
           toAddress: merchant.publicKey,
+          toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           tokenAccount: merchantTokenAccount,
           controllerProgram: controllerProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -562,6 +752,7 @@ describe("factory_error_tests", () => {
         membersStore,
         tokenMint,
         toAddress: merchant.publicKey,
+        toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         tokenAccount: merchantTokenAccount,
         controllerProgram: controllerProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -587,6 +778,7 @@ describe("factory_error_tests", () => {
           membersStore,
           tokenMint,
           toAddress: merchant.publicKey,
+          toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           tokenAccount: merchantTokenAccount,
           controllerProgram: controllerProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -632,6 +824,7 @@ describe("factory_error_tests", () => {
           membersStore,
           tokenMint,
           toAddress: merchant.publicKey,
+          toAddressDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           tokenAccount: merchantTokenAccount,
           controllerProgram: controllerProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -680,6 +873,7 @@ describe("factory_error_tests", () => {
         })
         .accounts({
           payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           factoryStore,
           requestAccount,
           controllerStore,
@@ -701,6 +895,33 @@ describe("factory_error_tests", () => {
     assert.notEqual(error, null, "Should have thrown an error but didn't");
     console.log("Error message:", error.message);
     assert.match(error.message, /AccountNotInitialized/);
+
+    // The merchant BTC address can only be omitted with a payout address
+    const omittedError = await catchError(
+      factoryProgram.methods
+        .burn({
+          amount: amount,
+        })
+        .accounts({
+          payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
+          factoryStore,
+          requestAccount,
+          controllerStore,
+          membersStore,
+          merchantBtcAddress: null,
+          merchantInfo,
+          tokenMint,
+          tokenAccount: merchantTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([merchant])
+        .rpc()
+    );
+    assert.notEqual(omittedError, null, "Should have thrown an error but didn't");
+    assert.match(omittedError.message, /InvalidBtcAddress/);
   });
 
   it("Set merchant BTC address and perform burn test", async () => {
@@ -753,6 +974,7 @@ describe("factory_error_tests", () => {
       })
       .accounts({
         payer: merchant.publicKey,
+        requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
         factoryStore,
         requestAccount,
         controllerStore,
@@ -775,6 +997,37 @@ describe("factory_error_tests", () => {
     assert.equal(requestData.status.pending !== undefined, true);
   });
 
+  it("Custodian attempts to confirm a burn paying out more than was burned", async () => {
+    const requestData = await factoryProgram.account.requestAccount.fetch(
+      requestAccount
+    );
+
+    const error = await catchError(
+      factoryProgram.methods
+        .confirmBurnRequest({
+          nonce: new BN(burnRequestNonce),
+          btcTxid: btcTxid,
+          btcAmountSent: amount,
+          btcNetworkFee: btcNetworkFee,
+          requestHash: requestData.hash,
+        })
+        .accounts({
+          payer: custodian.publicKey,
+          factoryStore,
+          requestAccount,
+          controllerStore,
+          membersStore,
+        })
+        .signers([custodian])
+        .rpc()
+    );
+
+    // Check error type
+    assert.notEqual(error, null, "Should have thrown an error but didn't");
+    console.log("Error message:", error.message);
+    assert.match(error.message, /InvalidBtcPayout/);
+  });
+
   it("Non-custodian attempts to confirm burn request", async () => {
     const requestData = await factoryProgram.account.requestAccount.fetch(
       requestAccount
@@ -785,6 +1038,8 @@ describe("factory_error_tests", () => {
         .confirmBurnRequest({
           nonce: new BN(burnRequestNonce),
           btcTxid: btcTxid,
+          btcAmountSent: amount.sub(btcNetworkFee),
+          btcNetworkFee: btcNetworkFee,
           requestHash: requestData.hash,
         })
         .accounts({
@@ -814,6 +1069,8 @@ describe("factory_error_tests", () => {
       .confirmBurnRequest({
         nonce: new BN(burnRequestNonce),
         btcTxid: btcTxid,
+        btcAmountSent: amount.sub(btcNetworkFee),
+        btcNetworkFee: btcNetworkFee,
         requestHash: requestData.hash,
       })
       .accounts({
@@ -832,6 +1089,8 @@ describe("factory_error_tests", () => {
         .confirmBurnRequest({
           nonce: new BN(burnRequestNonce),
           btcTxid: btcTxid,
+          btcAmountSent: amount.sub(btcNetworkFee),
+          btcNetworkFee: btcNetworkFee,
           requestHash: requestData.hash,
         })
         .accounts({
@@ -879,6 +1138,7 @@ describe("factory_error_tests", () => {
         })
         .accounts({
           payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           factoryStore,
           requestAccount,
           controllerStore,
@@ -929,6 +1189,7 @@ describe("factory_error_tests", () => {
         })
         .accounts({
           payer: merchant.publicKey,
+          requesterDenylistEntry: getDenylistEntry(membersProgram, merchant.publicKey),
           factoryStore,
           requestAccount,
           controllerStore,